@@ -0,0 +1,293 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+};
+
+use crate::{
+    checkpoint::{Checkpoint, Encode},
+    grid::Grid,
+    vector::{Vector, HEX_OFFSETS},
+};
+
+pub trait Space: Clone + PartialEq {
+    type Cell: Copy;
+    type State: Copy;
+
+    fn candidates<N>(&self, neighbours: N) -> Vec<Self::Cell>
+    where
+        N: Fn(Self::Cell) -> Vec<Self::Cell>;
+    fn get(&self, cell: Self::Cell) -> Self::State;
+    fn next<I>(&self, states: I) -> Self
+    where
+        I: IntoIterator<Item = (Self::Cell, Self::State)>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+pub struct Automaton<S, N, R> {
+    space: S,
+    generation: usize,
+    neighbourhood: N,
+    rule: R,
+}
+
+impl<S, N, R> Automaton<S, N, R>
+where
+    S: Space,
+    N: Fn(&S, S::Cell) -> Vec<S::Cell>,
+    R: Fn(S::State, &[S::State]) -> S::State,
+{
+    pub fn new(space: S, neighbourhood: N, rule: R) -> Self {
+        Automaton {
+            space,
+            generation: 0,
+            neighbourhood,
+            rule,
+        }
+    }
+
+    pub fn space(&self) -> &S {
+        &self.space
+    }
+
+    pub fn into_space(self) -> S {
+        self.space
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn step(&mut self) -> bool {
+        let space = &self.space;
+        let neighbourhood = &self.neighbourhood;
+
+        let states: Vec<(S::Cell, S::State)> = space
+            .candidates(|cell| neighbourhood(space, cell))
+            .into_iter()
+            .map(|cell| {
+                let neighbours: Vec<S::State> = neighbourhood(space, cell)
+                    .into_iter()
+                    .map(|neighbour| space.get(neighbour))
+                    .collect();
+                (cell, (self.rule)(space.get(cell), &neighbours))
+            })
+            .collect();
+
+        let next = space.next(states);
+        let changed = next != self.space;
+        self.space = next;
+        self.generation += 1;
+
+        changed
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    pub fn run_until_stable(&mut self, limit: usize) -> Result<usize, String> {
+        for _ in 0..limit {
+            if !self.step() {
+                return Ok(self.generation);
+            }
+        }
+
+        Err(format!("not stable after {} generations", limit))
+    }
+
+    pub fn find_cycle(&mut self, limit: usize) -> Result<Cycle, String>
+    where
+        S: Eq + Hash,
+    {
+        let mut seen: HashMap<S, usize> = HashMap::new();
+
+        for _ in 0..=limit {
+            if let Some(&start) = seen.get(&self.space) {
+                return Ok(Cycle {
+                    start,
+                    period: self.generation - start,
+                });
+            }
+
+            seen.insert(self.space.clone(), self.generation);
+            self.step();
+        }
+
+        Err(format!("no cycle within {} generations", limit))
+    }
+}
+
+/// Saves the space and generation; the neighbourhood and rule come from the code.
+impl<S: Encode, N, R> Checkpoint for Automaton<S, N, R> {
+    fn save(&self, out: &mut Vec<u8>) {
+        self.generation.encode(out);
+        self.space.encode(out);
+    }
+
+    fn restore(&mut self, input: &mut &[u8]) -> Result<(), String> {
+        self.generation = Encode::decode(input)?;
+        self.space = Encode::decode(input)?;
+        Ok(())
+    }
+}
+
+impl<T: Copy + PartialEq> Space for Grid<T> {
+    type Cell = (usize, usize);
+    type State = T;
+
+    fn candidates<N>(&self, _: N) -> Vec<Self::Cell>
+    where
+        N: Fn(Self::Cell) -> Vec<Self::Cell>,
+    {
+        self.positions().map(|(position, _)| position).collect()
+    }
+
+    fn get(&self, position: Self::Cell) -> Self::State {
+        self[position]
+    }
+
+    fn next<I>(&self, states: I) -> Self
+    where
+        I: IntoIterator<Item = (Self::Cell, Self::State)>,
+    {
+        let mut next = self.clone();
+        for ((x, y), state) in states {
+            next.set(x, y, state);
+        }
+        next
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sparse<C: Eq + Hash> {
+    active: HashSet<C>,
+}
+
+impl<C: Eq + Hash> Sparse<C> {
+    pub fn new() -> Self {
+        Sparse {
+            active: HashSet::new(),
+        }
+    }
+
+    pub fn insert(&mut self, cell: C) -> bool {
+        self.active.insert(cell)
+    }
+
+    pub fn contains(&self, cell: &C) -> bool {
+        self.active.contains(cell)
+    }
+
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &C> {
+        self.active.iter()
+    }
+}
+
+impl<C: Eq + Hash> Default for Sparse<C> {
+    fn default() -> Self {
+        Sparse::new()
+    }
+}
+
+impl<C: Eq + Hash> FromIterator<C> for Sparse<C> {
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        Sparse {
+            active: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<C: Eq + Hash> Hash for Sparse<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Sum per-cell hashes so equal sets hash equally in any iteration order.
+        let sum = self.active.iter().fold(0u64, |acc, cell| {
+            let mut hasher = DefaultHasher::new();
+            cell.hash(&mut hasher);
+            acc.wrapping_add(hasher.finish())
+        });
+
+        self.active.len().hash(state);
+        sum.hash(state);
+    }
+}
+
+impl<C: Encode + Eq + Hash + Ord> Encode for Sparse<C> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.active.encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        Ok(Sparse {
+            active: Encode::decode(input)?,
+        })
+    }
+}
+
+impl<C: Copy + Eq + Hash> Space for Sparse<C> {
+    type Cell = C;
+    type State = bool;
+
+    fn candidates<N>(&self, neighbours: N) -> Vec<Self::Cell>
+    where
+        N: Fn(Self::Cell) -> Vec<Self::Cell>,
+    {
+        let mut candidates: HashSet<C> = self.active.clone();
+        for &cell in &self.active {
+            candidates.extend(neighbours(cell));
+        }
+
+        candidates.into_iter().collect()
+    }
+
+    fn get(&self, cell: Self::Cell) -> Self::State {
+        self.active.contains(&cell)
+    }
+
+    fn next<I>(&self, states: I) -> Self
+    where
+        I: IntoIterator<Item = (Self::Cell, Self::State)>,
+    {
+        states
+            .into_iter()
+            .filter_map(|(cell, active)| if active { Some(cell) } else { None })
+            .collect()
+    }
+}
+
+pub fn moore<const D: usize>(cell: [isize; D]) -> Vec<[isize; D]> {
+    Vector::moore_offsets()
+        .into_iter()
+        .map(|offset| (Vector(cell) + offset).0)
+        .collect()
+}
+
+/// Hex cells in doubled coordinates, where east is `(2, 0)` and north-east `(1, 1)`.
+pub fn hex_neighbours((x, y): (isize, isize)) -> Vec<(isize, isize)> {
+    HEX_OFFSETS
+        .iter()
+        .map(|&offset| {
+            let Vector([x, y]) = Vector([x, y]) + offset;
+            (x, y)
+        })
+        .collect()
+}
+
+pub fn count_live(states: &[bool]) -> usize {
+    states.iter().filter(|&&state| state).count()
+}
@@ -0,0 +1,193 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::Solution;
+
+pub const CSV_HEADER: &str = "day,phase,runs,min_ns,median_ns,max_ns";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part1),
+            "part2" => Ok(Phase::Part2),
+            x => Err(format!("invalid phase: {}", x)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    pub samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn summary(&self) -> Summary {
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+
+        let median = match sorted.len() {
+            0 => Duration::default(),
+            n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            n => sorted[n / 2],
+        };
+
+        Summary {
+            day: self.day,
+            phase: self.phase,
+            runs: sorted.len(),
+            min: sorted.first().copied().unwrap_or_default(),
+            median,
+            max: sorted.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub day: u8,
+    pub phase: Phase,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.day,
+            self.phase,
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+impl FromStr for Summary {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split(',').collect();
+        if fields.len() != 6 {
+            return Err(format!("invalid bench row: {}", s));
+        }
+
+        let nanos = |field: &str| {
+            field
+                .parse()
+                .map(Duration::from_nanos)
+                .map_err(|e| format!("invalid duration: {} ({:?})", field, e))
+        };
+
+        Ok(Summary {
+            day: fields[0]
+                .parse()
+                .map_err(|e| format!("invalid day: {} ({:?})", fields[0], e))?,
+            phase: fields[1].parse()?,
+            runs: fields[2]
+                .parse()
+                .map_err(|e| format!("invalid runs: {} ({:?})", fields[2], e))?,
+            min: nanos(fields[3])?,
+            median: nanos(fields[4])?,
+            max: nanos(fields[5])?,
+        })
+    }
+}
+
+pub fn bench<S: Solution>(input: &str, runs: usize) -> Result<Vec<Timing>, String> {
+    let parsed = S::parse(input).map_err(|e| e.render(input))?;
+
+    let parse = time(runs, || {
+        S::parse(black_box(input))
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    })?;
+    let params = S::Params::default();
+    let part1 = time(runs, || S::part1(black_box(&parsed), &params).map(|_| ()))?;
+    let part2 = time(runs, || S::part2(black_box(&parsed), &params).map(|_| ()))?;
+
+    Ok(vec![
+        Timing {
+            day: S::DAY,
+            phase: Phase::Parse,
+            samples: parse,
+        },
+        Timing {
+            day: S::DAY,
+            phase: Phase::Part1,
+            samples: part1,
+        },
+        Timing {
+            day: S::DAY,
+            phase: Phase::Part2,
+            samples: part2,
+        },
+    ])
+}
+
+fn time<F>(runs: usize, mut f: F) -> Result<Vec<Duration>, String>
+where
+    F: FnMut() -> Result<(), String>,
+{
+    let mut samples = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+
+    Ok(samples)
+}
+
+pub fn to_csv(summaries: &[Summary]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+
+    for summary in summaries {
+        csv.push_str(&summary.to_csv_row());
+        csv.push('\n');
+    }
+
+    csv
+}
+
+pub fn from_csv(csv: &str) -> Result<BTreeMap<(u8, Phase), Summary>, String> {
+    csv.lines()
+        .filter(|line| !line.is_empty() && *line != CSV_HEADER)
+        .map(|line| {
+            let summary = Summary::from_str(line)?;
+            Ok(((summary.day, summary.phase), summary))
+        })
+        .collect()
+}
@@ -0,0 +1,410 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, Mul, Rem},
+    str::FromStr,
+};
+
+use crate::Answer;
+
+/// An unsigned integer of any size, stored as little-endian 32-bit limbs with no trailing
+/// zero limbs, so zero has none.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0, |acc, &limb| acc << 32 | limb as u128),
+        )
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+
+        BigUint { limbs }
+    }
+
+    fn bits(&self) -> usize {
+        self.limbs.last().map_or(0, |&top| {
+            self.limbs.len() * 32 - top.leading_zeros() as usize
+        })
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        self.limbs
+            .get(i / 32)
+            .is_some_and(|&limb| limb >> (i % 32) & 1 == 1)
+    }
+
+    /// `self * factor + addend`, for a non-zero `factor`.
+    fn mul_small_add(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+        for limb in &mut self.limbs {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut rem = 0u64;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let current = rem << 32 | limb as u64;
+            quotient[i] = (current / divisor as u64) as u32;
+            rem = current % divisor as u64;
+        }
+
+        (BigUint::from_limbs(quotient), rem as u32)
+    }
+
+    /// `self - other`, which must not be negative.
+    fn sub(&self, other: &BigUint) -> BigUint {
+        let mut limbs = self.limbs.clone();
+        let mut borrow = 0i64;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let diff = *limb as i64 - other.limbs.get(i).copied().unwrap_or(0) as i64 - borrow;
+            borrow = (diff < 0) as i64;
+            *limb = diff.rem_euclid(1 << 32) as u32;
+        }
+
+        BigUint::from_limbs(limbs)
+    }
+
+    fn shl1_with(&self, bit: bool) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry = bit as u32;
+        for &limb in &self.limbs {
+            limbs.push(limb << 1 | carry);
+            carry = limb >> 31;
+        }
+        limbs.push(carry);
+
+        BigUint::from_limbs(limbs)
+    }
+
+    /// Returns the quotient and remainder of `self / divisor`, or `None` if it is zero.
+    pub fn div_rem(&self, divisor: &BigUint) -> Option<(BigUint, BigUint)> {
+        match divisor.limbs.len() {
+            0 => None,
+            1 => {
+                let (quotient, rem) = self.div_rem_small(divisor.limbs[0]);
+                Some((quotient, BigUint::from(rem)))
+            }
+            _ => {
+                let mut quotient = vec![0; self.limbs.len()];
+                let mut rem = BigUint::zero();
+                for i in (0..self.bits()).rev() {
+                    rem = rem.shl1_with(self.bit(i));
+                    if rem >= *divisor {
+                        rem = rem.sub(divisor);
+                        quotient[i / 32] |= 1 << (i % 32);
+                    }
+                }
+
+                Some((BigUint::from_limbs(quotient), rem))
+            }
+        }
+    }
+}
+
+macro_rules! big_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(n: $t) -> Self {
+                    let n = n as u128;
+                    BigUint::from_limbs((0..4).map(|i| (n >> (32 * i)) as u32).collect())
+                }
+            }
+        )*
+    };
+}
+
+big_from!(u8, u16, u32, u64, u128, usize);
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let len = self.limbs.len().max(rhs.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0u64;
+        for i in 0..len {
+            let sum = self.limbs.get(i).copied().unwrap_or(0) as u64
+                + rhs.limbs.get(i).copied().unwrap_or(0) as u64
+                + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let product = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Rem for &BigUint {
+    type Output = BigUint;
+
+    /// Panics if `rhs` is zero, like the primitive integers.
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs)
+            .map(|(_, rem)| rem)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+macro_rules! owned_ops {
+    ($t:ty: $($trait:ident $f:ident),*) => {
+        $(
+            impl $trait for $t {
+                type Output = $t;
+
+                fn $f(self, rhs: $t) -> $t {
+                    (&self).$f(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+owned_ops!(BigUint: Add add, Mul mul, Rem rem);
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.div_rem_small(CHUNK);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:09}", chunk));
+        }
+
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("invalid number: {}", s));
+        }
+
+        let mut n = BigUint::zero();
+        for digit in s.bytes() {
+            n.mul_small_add(10, (digit - b'0') as u32);
+        }
+
+        Ok(n)
+    }
+}
+
+/// A `u128` that switches to a `BigUint` instead of overflowing. Values that fit in a `u128`
+/// are always `Small`, so equal numbers compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Number {
+    Small(u128),
+    Big(BigUint),
+}
+
+impl Number {
+    pub fn to_u128(&self) -> Option<u128> {
+        match self {
+            Number::Small(n) => Some(*n),
+            Number::Big(_) => None,
+        }
+    }
+
+    fn into_big(self) -> BigUint {
+        match self {
+            Number::Small(n) => BigUint::from(n),
+            Number::Big(n) => n,
+        }
+    }
+}
+
+impl Default for Number {
+    fn default() -> Self {
+        Number::Small(0)
+    }
+}
+
+impl From<BigUint> for Number {
+    fn from(n: BigUint) -> Self {
+        match n.to_u128() {
+            Some(n) => Number::Small(n),
+            None => Number::Big(n),
+        }
+    }
+}
+
+macro_rules! number_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Number {
+                fn from(n: $t) -> Self {
+                    Number::Small(n as u128)
+                }
+            }
+        )*
+    };
+}
+
+number_from!(u8, u16, u32, u64, u128, usize);
+
+impl Ord for Number {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Number::Small(a), Number::Small(b)) => a.cmp(b),
+            (Number::Small(_), Number::Big(_)) => Ordering::Less,
+            (Number::Big(_), Number::Small(_)) => Ordering::Greater,
+            (Number::Big(a), Number::Big(b)) => a.cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, rhs: Number) -> Number {
+        match (&self, &rhs) {
+            (Number::Small(a), Number::Small(b)) => match a.checked_add(*b) {
+                Some(sum) => Number::Small(sum),
+                None => Number::Big(BigUint::from(*a) + BigUint::from(*b)),
+            },
+            _ => Number::from(self.into_big() + rhs.into_big()),
+        }
+    }
+}
+
+impl Mul for Number {
+    type Output = Number;
+
+    fn mul(self, rhs: Number) -> Number {
+        match (&self, &rhs) {
+            (Number::Small(a), Number::Small(b)) => match a.checked_mul(*b) {
+                Some(product) => Number::Small(product),
+                None => Number::Big(BigUint::from(*a) * BigUint::from(*b)),
+            },
+            _ => Number::from(self.into_big() * rhs.into_big()),
+        }
+    }
+}
+
+impl Rem for Number {
+    type Output = Number;
+
+    fn rem(self, rhs: Number) -> Number {
+        match (&self, &rhs) {
+            (Number::Small(a), Number::Small(b)) => Number::Small(a % b),
+            _ => Number::from(self.into_big() % rhs.into_big()),
+        }
+    }
+}
+
+impl Sum for Number {
+    fn sum<I: Iterator<Item = Number>>(iter: I) -> Number {
+        iter.fold(Number::Small(0), Add::add)
+    }
+}
+
+impl Product for Number {
+    fn product<I: Iterator<Item = Number>>(iter: I) -> Number {
+        iter.fold(Number::Small(1), Mul::mul)
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Small(n) => fmt::Display::fmt(n, f),
+            Number::Big(n) => fmt::Display::fmt(n, f),
+        }
+    }
+}
+
+impl FromStr for Number {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<BigUint>().map(Number::from)
+    }
+}
+
+impl Answer for Number {
+    const NUMERIC: bool = true;
+}
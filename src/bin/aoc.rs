@@ -0,0 +1,830 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, Instant},
+};
+
+use adventofcode_2020::{
+    bench::{self, Summary},
+    bench_day,
+    client::{self, Client, Curl, Verdict},
+    crosscheck::{quick_params, Disagreement},
+    crosscheck_day, gen,
+    inputs::{self, Source, CACHE_DIR},
+    params, print_answers, print_trace,
+    runner::{self, Job},
+    solve_day, solve_inputs, trace,
+    verify::{self, Status},
+    Format, Options, DAYS,
+};
+
+const USAGE: &str =
+    "usage: aoc <day> [--part N] [--lenient] [--format text|json] [--explain[=text|json]]
+               [--progress] [--checkpoint DIR] [--param KEY=VALUE]... [--config FILE]
+               [input-file|input-dir]...
+       aoc all [--lenient] [--format text|json] [--explain[=text|json]]
+               [--param dayN.KEY=VALUE]... [--config FILE] [--jobs N] [--timeout SECS]
+               [--answers FILE] [input-dir]...
+       aoc bench [day] [--runs N] [--output FILE] [--baseline FILE] [input-dir]
+       aoc verify [--answers FILE] [--record] [input-dir]
+       aoc gen <day> [--seed N] [--size N] [output-file]
+       aoc crosscheck [day]... [--seeds N] [--size N] [--param dayN.KEY=VALUE]...
+               [--config FILE]
+       aoc fetch <day>... [--config FILE]
+       aoc submit <day> <part> [answer] [--answers FILE] [--config FILE]";
+
+const CONFIG_FILE: &str = "aoc.toml";
+const ANSWERS_FILE: &str = "answers.toml";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    match args.first().map(String::as_str) {
+        Some("all") => {
+            let mut options = Options::default();
+            let mut format = Format::default();
+            let mut overrides = Vec::new();
+            let mut config_path = None;
+            let mut settings = AllSettings::default();
+            let mut input_dirs = Vec::new();
+
+            let mut iter = args.iter().skip(1);
+            while let Some(arg) = iter.next() {
+                match arg.as_str() {
+                    "--lenient" => {
+                        options.lenient = true;
+                    }
+                    "--jobs" => {
+                        let val = iter
+                            .next()
+                            .ok_or_else(|| format!("missing jobs\n{}", USAGE))?;
+                        settings.threads = val
+                            .parse()
+                            .ok()
+                            .filter(|&threads| threads > 0)
+                            .ok_or_else(|| format!("invalid jobs: {}\n{}", val, USAGE))?;
+                    }
+                    "--timeout" => {
+                        let val = iter
+                            .next()
+                            .ok_or_else(|| format!("missing timeout\n{}", USAGE))?;
+                        settings.timeout = val
+                            .parse()
+                            .ok()
+                            .filter(|&secs: &f64| secs > 0.0 && secs.is_finite())
+                            .map(Duration::from_secs_f64)
+                            .ok_or_else(|| format!("invalid timeout: {}\n{}", val, USAGE))?;
+                    }
+                    "--answers" => {
+                        settings.answers_path = iter
+                            .next()
+                            .ok_or_else(|| format!("missing answers file\n{}", USAGE))?;
+                    }
+                    "--format" => {
+                        format = parse_format(iter.next())?;
+                    }
+                    "--param" => match parse_param(iter.next())? {
+                        (Some(day), key, value) => overrides.push((day, key, value)),
+                        (None, key, _) => {
+                            return Err(format!(
+                                "parameter {} needs a dayN. prefix with `aoc all`\n{}",
+                                key, USAGE
+                            ));
+                        }
+                    },
+                    "--config" => {
+                        config_path = Some(
+                            iter.next()
+                                .ok_or_else(|| format!("missing config file\n{}", USAGE))?,
+                        );
+                    }
+                    x if x.starts_with("--") => {
+                        options.explain = Some(
+                            trace::parse_flag(x)
+                                .ok_or_else(|| format!("unexpected argument: {}", x))
+                                .and_then(|format| format)
+                                .map_err(|e| format!("{}\n{}", e, USAGE))?,
+                        );
+                    }
+                    path => {
+                        input_dirs.push(PathBuf::from(path));
+                    }
+                }
+            }
+
+            if input_dirs.is_empty() {
+                input_dirs.push(PathBuf::from(CACHE_DIR));
+            }
+
+            let mut params = load_config(config_path)?;
+            for (day, key, value) in overrides {
+                params.entry(day).or_default().push((key, value));
+            }
+
+            run_all(&input_dirs, &options, &params, &settings, format)
+        }
+        Some("bench") => run_bench(&args[1..]),
+        Some("verify") => run_verify(&args[1..]),
+        Some("gen") => run_gen(&args[1..]),
+        Some("crosscheck") => run_crosscheck(&args[1..]),
+        Some("fetch") => run_fetch(&args[1..]),
+        Some("submit") => run_submit(&args[1..]),
+        Some("-h") | Some("--help") | None => Err(USAGE.to_string()),
+        Some(day) => {
+            let day: u8 = day
+                .parse()
+                .map_err(|_| format!("invalid day: {}\n{}", day, USAGE))?;
+
+            let mut options = Options::default();
+            let mut format = Format::default();
+            let mut overrides = Vec::new();
+            let mut config_path = None;
+            let mut paths = Vec::new();
+
+            let mut iter = args.iter().skip(1);
+            while let Some(arg) = iter.next() {
+                match arg.as_str() {
+                    "--part" => {
+                        let val = iter
+                            .next()
+                            .ok_or_else(|| format!("missing part\n{}", USAGE))?;
+                        options.part = Some(
+                            val.parse()
+                                .map_err(|_| format!("invalid part: {}\n{}", val, USAGE))?,
+                        );
+                    }
+                    "--lenient" => {
+                        options.lenient = true;
+                    }
+                    "--progress" => {
+                        options.progress = true;
+                    }
+                    "--checkpoint" => {
+                        options.checkpoint =
+                            Some(PathBuf::from(iter.next().ok_or_else(|| {
+                                format!("missing checkpoint dir\n{}", USAGE)
+                            })?));
+                    }
+                    "--format" => {
+                        format = parse_format(iter.next())?;
+                    }
+                    "--param" => match parse_param(iter.next())? {
+                        (Some(other), key, _) if other != day => {
+                            return Err(format!(
+                                "parameter day{}.{} does not apply to day {}",
+                                other, key, day
+                            ));
+                        }
+                        (_, key, value) => overrides.push((key, value)),
+                    },
+                    "--config" => {
+                        config_path = Some(
+                            iter.next()
+                                .ok_or_else(|| format!("missing config file\n{}", USAGE))?,
+                        );
+                    }
+                    x if x.starts_with("--") => {
+                        options.explain = Some(
+                            trace::parse_flag(x)
+                                .ok_or_else(|| format!("unexpected argument: {}", x))
+                                .and_then(|format| format)
+                                .map_err(|e| format!("{}\n{}", e, USAGE))?,
+                        );
+                    }
+                    path => {
+                        paths.push(PathBuf::from(path));
+                    }
+                }
+            }
+
+            options.params = load_config(config_path)?.remove(&day).unwrap_or_default();
+            options.params.extend(overrides);
+
+            let sources = inputs::resolve(day, &paths, Path::new(CACHE_DIR))?;
+
+            solve_inputs(day, &sources, &options, format)
+        }
+    }
+}
+
+struct AllSettings<'a> {
+    threads: usize,
+    timeout: Duration,
+    answers_path: &'a str,
+}
+
+impl Default for AllSettings<'_> {
+    fn default() -> Self {
+        AllSettings {
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: runner::DEFAULT_TIMEOUT,
+            answers_path: ANSWERS_FILE,
+        }
+    }
+}
+
+fn run_all(
+    input_dirs: &[PathBuf],
+    options: &Options,
+    params: &BTreeMap<u8, Vec<(String, String)>>,
+    settings: &AllSettings,
+    format: Format,
+) -> Result<(), String> {
+    let known = if Path::new(settings.answers_path).exists() {
+        verify::parse_answers(&read_file(Path::new(settings.answers_path))?)?
+    } else {
+        Vec::new()
+    };
+
+    let jobs = DAYS
+        .flat_map(|day| {
+            input_dirs.iter().map(move |dir| Job {
+                day,
+                source: Source::File(inputs::day_file(dir, day)),
+                options: Options {
+                    params: params.get(&day).cloned().unwrap_or_default(),
+                    ..options.clone()
+                },
+            })
+        })
+        .collect();
+
+    let start = Instant::now();
+    let finished = runner::run(jobs, settings.threads, settings.timeout);
+    let wall = start.elapsed();
+    let labelled = input_dirs.len() > 1;
+
+    for finished in &finished {
+        let job = &finished.job;
+        match &finished.result {
+            Ok((input, answers)) => {
+                if let Some(warning) = input.warning {
+                    eprintln!("warning: {}: {}", input.source, warning);
+                }
+
+                if let Some(explain) = options.explain {
+                    print_trace(job.day, answers, explain);
+                }
+
+                match format {
+                    Format::Text => {
+                        for skipped in &answers.skipped {
+                            eprintln!("warning: skipped {}", skipped.render(&input.text));
+                        }
+                    }
+                    Format::Json => print_answers(job.day, answers, input, labelled, format),
+                }
+            }
+            Err(e) => eprintln!("Day {} ({}): {}", job.day, job.source, e),
+        }
+    }
+
+    let rows = runner::rows(&finished, &known);
+    if format == Format::Text {
+        println!("{}", runner::table(&rows, wall, labelled));
+    }
+
+    if rows.iter().all(|row| row.status == runner::Status::Ok) {
+        Ok(())
+    } else {
+        Err("some days failed".to_string())
+    }
+}
+
+fn run_bench(args: &[String]) -> Result<(), String> {
+    let mut days = None;
+    let mut runs = 10;
+    let mut output = "bench.csv";
+    let mut baseline = None;
+    let mut input_dir = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--runs" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| format!("missing runs\n{}", USAGE))?;
+                runs = val
+                    .parse()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or_else(|| format!("invalid runs: {}\n{}", val, USAGE))?;
+            }
+            "--output" => {
+                output = iter
+                    .next()
+                    .ok_or_else(|| format!("missing output\n{}", USAGE))?;
+            }
+            "--baseline" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("missing baseline\n{}", USAGE))?;
+                baseline = Some(bench::from_csv(&read_file(Path::new(path))?)?);
+            }
+            x => match x.parse::<u8>() {
+                Ok(day) if days.is_none() => {
+                    days = Some(day..=day);
+                }
+                _ if input_dir.is_none() => {
+                    input_dir = Some(x);
+                }
+                _ => {
+                    return Err(format!("unexpected argument: {}\n{}", x, USAGE));
+                }
+            },
+        }
+    }
+
+    let input_dir = Path::new(input_dir.unwrap_or(CACHE_DIR));
+    let mut summaries: Vec<Summary> = Vec::new();
+    let mut failed = false;
+
+    for day in days.unwrap_or(DAYS) {
+        println!("Day {}", day);
+
+        let result = inputs::load(Source::File(inputs::day_file(input_dir, day)))
+            .and_then(|input| bench_day(day, &input.text, runs));
+
+        match result {
+            Ok(timings) => {
+                for timing in timings {
+                    let summary = timing.summary();
+                    let change = baseline
+                        .as_ref()
+                        .and_then(|baseline| baseline.get(&(summary.day, summary.phase)))
+                        .filter(|old| old.median.as_nanos() > 0)
+                        .map(|old| {
+                            let old = old.median.as_secs_f64();
+                            let new = summary.median.as_secs_f64();
+                            format!("  ({:+.1}%)", (new - old) / old * 100.0)
+                        })
+                        .unwrap_or_default();
+
+                    println!(
+                        "  {}  min {:?}  median {:?}  max {:?}{}",
+                        summary.phase, summary.min, summary.median, summary.max, change
+                    );
+                    summaries.push(summary);
+                }
+            }
+            Err(e) => {
+                failed = true;
+                eprintln!("Day {}: {}", day, e);
+            }
+        }
+    }
+
+    fs::write(output, bench::to_csv(&summaries))
+        .map_err(|e| format!("failed to write {}: {}", output, e))?;
+
+    if failed {
+        Err("some days failed".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn run_verify(args: &[String]) -> Result<(), String> {
+    let mut answers_path = ANSWERS_FILE;
+    let mut record = false;
+    let mut input_dir = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--answers" => {
+                answers_path = iter
+                    .next()
+                    .ok_or_else(|| format!("missing answers file\n{}", USAGE))?;
+            }
+            "--record" => {
+                record = true;
+            }
+            path if input_dir.is_none() => {
+                input_dir = Some(path);
+            }
+            x => {
+                return Err(format!("unexpected argument: {}\n{}", x, USAGE));
+            }
+        }
+    }
+
+    let mut answers = if Path::new(answers_path).exists() {
+        verify::parse_answers(&read_file(Path::new(answers_path))?)?
+    } else {
+        Vec::new()
+    };
+
+    let checks = verify::verify(&answers, Path::new(input_dir.unwrap_or(CACHE_DIR)));
+    for check in &checks {
+        match check.status {
+            Status::Mismatch { .. } | Status::Failed(_) => eprintln!("{}", check),
+            _ => println!("{}", check),
+        }
+    }
+
+    let count = |f: fn(&Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+    println!(
+        "{} ok, {} mismatched, {} new, {} missing, {} failed",
+        count(|s| matches!(s, Status::Match(_))),
+        count(|s| matches!(s, Status::Mismatch { .. })),
+        count(|s| matches!(s, Status::New(_))),
+        count(|s| matches!(s, Status::Missing)),
+        count(|s| matches!(s, Status::Failed(_))),
+    );
+
+    if record {
+        let recorded = verify::record(&mut answers, &checks);
+        if recorded > 0 {
+            fs::write(answers_path, verify::to_toml(&answers))
+                .map_err(|e| format!("failed to write {}: {}", answers_path, e))?;
+            println!("recorded {} new answers in {}", recorded, answers_path);
+        }
+    }
+
+    if checks.iter().any(|c| c.status.is_failure()) {
+        Err("verification failed".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn run_gen(args: &[String]) -> Result<(), String> {
+    let day = args
+        .first()
+        .ok_or_else(|| format!("missing day\n{}", USAGE))?;
+    let day: u8 = day
+        .parse()
+        .map_err(|_| format!("invalid day: {}\n{}", day, USAGE))?;
+
+    let mut seed = 0;
+    let mut size = None;
+    let mut output = None;
+
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| format!("missing seed\n{}", USAGE))?;
+                seed = val
+                    .parse()
+                    .map_err(|_| format!("invalid seed: {}\n{}", val, USAGE))?;
+            }
+            "--size" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| format!("missing size\n{}", USAGE))?;
+                size = Some(
+                    val.parse()
+                        .map_err(|_| format!("invalid size: {}\n{}", val, USAGE))?,
+                );
+            }
+            path if output.is_none() => {
+                output = Some(path);
+            }
+            x => {
+                return Err(format!("unexpected argument: {}\n{}", x, USAGE));
+            }
+        }
+    }
+
+    let generated = gen::generate(day, seed, size)?;
+
+    match output {
+        Some(path) => fs::write(path, &generated.input)
+            .map_err(|e| format!("failed to write {}: {}", path, e))?,
+        None => print!("{}", generated.input),
+    }
+
+    for part in 1..=2 {
+        eprintln!(
+            "Part {}: {}",
+            part,
+            generated.part(part).unwrap_or("unknown")
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks each day's solver against its reference solver on generated inputs, stopping at the
+/// first seed that makes them disagree.
+fn run_crosscheck(args: &[String]) -> Result<(), String> {
+    let mut days = Vec::new();
+    let mut seeds = 10;
+    let mut size = None;
+    let mut overrides = Vec::new();
+    let mut config_path = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--seeds" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| format!("missing seeds\n{}", USAGE))?;
+                seeds = val
+                    .parse()
+                    .ok()
+                    .filter(|&seeds| seeds > 0)
+                    .ok_or_else(|| format!("invalid seeds: {}\n{}", val, USAGE))?;
+            }
+            "--size" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| format!("missing size\n{}", USAGE))?;
+                size = Some(
+                    val.parse()
+                        .map_err(|_| format!("invalid size: {}\n{}", val, USAGE))?,
+                );
+            }
+            "--param" => match parse_param(iter.next())? {
+                (Some(day), key, value) => overrides.push((day, key, value)),
+                (None, key, _) => {
+                    return Err(format!(
+                        "parameter {} needs a dayN. prefix with `aoc crosscheck`\n{}",
+                        key, USAGE
+                    ));
+                }
+            },
+            "--config" => {
+                config_path = Some(
+                    iter.next()
+                        .ok_or_else(|| format!("missing config file\n{}", USAGE))?,
+                );
+            }
+            x if x.starts_with("--") => {
+                return Err(format!("unexpected argument: {}\n{}", x, USAGE));
+            }
+            day => days.push(parse_day(day)?),
+        }
+    }
+
+    let explicit = !days.is_empty();
+    if !explicit {
+        days = DAYS.collect();
+    }
+
+    let mut params = load_config(config_path)?;
+    for (day, key, value) in overrides {
+        params.entry(day).or_default().push((key, value));
+    }
+
+    let mut failed = false;
+    for day in days {
+        let mut day_params = quick_params(day);
+        day_params.extend(params.get(&day).cloned().unwrap_or_default());
+
+        match crosscheck_seeds(day, seeds, size, &day_params) {
+            Ok(Outcome::Agreed) => println!("Day {}: {} inputs agree", day, seeds),
+            Ok(Outcome::Disagreed(seed, disagreements)) => {
+                failed = true;
+                for disagreement in disagreements {
+                    print_disagreement(day, seed, &disagreement);
+                }
+            }
+            Ok(Outcome::NoReference) if explicit => println!("Day {}: no reference solver", day),
+            Ok(Outcome::NoReference) => {}
+            Err(e) => {
+                failed = true;
+                eprintln!("Day {}: {}", day, e);
+            }
+        }
+    }
+
+    if failed {
+        Err("crosscheck failed".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+enum Outcome {
+    NoReference,
+    Agreed,
+    /// The first seed whose input made the solvers disagree, and how.
+    Disagreed(u64, Vec<Disagreement>),
+}
+
+fn crosscheck_seeds(
+    day: u8,
+    seeds: u64,
+    size: Option<usize>,
+    params: &[(String, String)],
+) -> Result<Outcome, String> {
+    for seed in 0..seeds {
+        let generated = gen::generate(day, seed, size)?;
+        match crosscheck_day(day, &generated.input, params)
+            .map_err(|e| format!("seed {}: {}", seed, e))?
+        {
+            None => return Ok(Outcome::NoReference),
+            Some(disagreements) if !disagreements.is_empty() => {
+                return Ok(Outcome::Disagreed(seed, disagreements))
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(Outcome::Agreed)
+}
+
+fn print_disagreement(day: u8, seed: u64, disagreement: &Disagreement) {
+    let outcome = |result: &Result<String, String>| match result {
+        Ok(answer) => answer.clone(),
+        Err(e) => format!("error ({})", e),
+    };
+
+    println!(
+        "Day {} part {}, seed {}: solver gave {}, reference gave {}",
+        day,
+        disagreement.part,
+        seed,
+        outcome(&disagreement.solver),
+        outcome(&disagreement.reference)
+    );
+    println!("minimal input:");
+    print!("{}", disagreement.input);
+}
+
+fn run_fetch(args: &[String]) -> Result<(), String> {
+    let mut days = Vec::new();
+    let mut config_path = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--config" => {
+                config_path = Some(
+                    iter.next()
+                        .ok_or_else(|| format!("missing config file\n{}", USAGE))?,
+                );
+            }
+            day => {
+                days.push(parse_day(day)?);
+            }
+        }
+    }
+
+    if days.is_empty() {
+        return Err(format!("missing day\n{}", USAGE));
+    }
+
+    let session = client::session(read_config(config_path)?.as_deref())?;
+    let mut client = Client::new(Curl, session);
+    let mut failed = false;
+
+    for day in days {
+        match client.fetch(day) {
+            Ok(path) => println!("Day {}: {}", day, path.display()),
+            Err(e) => {
+                failed = true;
+                eprintln!("Day {}: {}", day, e);
+            }
+        }
+    }
+
+    if failed {
+        Err("some days failed".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn run_submit(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut answers_path = ANSWERS_FILE;
+    let mut config_path = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--answers" => {
+                answers_path = iter
+                    .next()
+                    .ok_or_else(|| format!("missing answers file\n{}", USAGE))?;
+            }
+            "--config" => {
+                config_path = Some(
+                    iter.next()
+                        .ok_or_else(|| format!("missing config file\n{}", USAGE))?,
+                );
+            }
+            x if x.starts_with("--") => {
+                return Err(format!("unexpected argument: {}\n{}", x, USAGE));
+            }
+            x => positional.push(x),
+        }
+    }
+
+    let (day, part, answer) = match positional.as_slice() {
+        [day, part] => (parse_day(day)?, *part, None),
+        [day, part, answer] => (parse_day(day)?, *part, Some(answer.to_string())),
+        _ => return Err(format!("expected a day, a part and an answer\n{}", USAGE)),
+    };
+    let part: u8 = part
+        .parse()
+        .ok()
+        .filter(|part| (1..=2).contains(part))
+        .ok_or_else(|| format!("invalid part: {}\n{}", part, USAGE))?;
+
+    let config = read_config(config_path)?;
+    let mut client = Client::new(Curl, client::session(config.as_deref())?);
+
+    // Without an explicit answer, solve the cached input, fetching it first if needed.
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = inputs::load(Source::File(client.fetch(day)?))?;
+            let options = Options {
+                part: Some(part),
+                params: match &config {
+                    Some(config) => params::parse_config(config)?
+                        .remove(&day)
+                        .unwrap_or_default(),
+                    None => Vec::new(),
+                },
+                ..Options::default()
+            };
+
+            solve_day(day, &input.text, &options)?
+                .parts
+                .pop()
+                .map(|part| part.answer)
+                .ok_or_else(|| "no answer produced".to_string())?
+        }
+    };
+
+    let mut answers = if Path::new(answers_path).exists() {
+        verify::parse_answers(&read_file(Path::new(answers_path))?)?
+    } else {
+        Vec::new()
+    };
+
+    let verdict = client.submit(day, part, &answer, &mut answers)?;
+    println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+
+    match verdict {
+        Verdict::Correct | Verdict::Incorrect(_) => {
+            fs::write(answers_path, verify::to_toml(&answers))
+                .map_err(|e| format!("failed to write {}: {}", answers_path, e))?;
+        }
+        Verdict::TooSoon(_) | Verdict::AlreadySolved => {}
+    }
+
+    if verdict == Verdict::Correct {
+        Ok(())
+    } else {
+        Err("answer was not accepted".to_string())
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse()
+        .ok()
+        .filter(|day| DAYS.contains(day))
+        .ok_or_else(|| format!("invalid day: {}\n{}", day, USAGE))
+}
+
+fn parse_param(val: Option<&String>) -> Result<(Option<u8>, String, String), String> {
+    let val = val.ok_or_else(|| format!("missing parameter\n{}", USAGE))?;
+
+    params::parse_override(val).map_err(|e| format!("{}\n{}", e, USAGE))
+}
+
+/// Reads `--config FILE`, or `aoc.toml` in the working directory when it exists.
+fn read_config(path: Option<&String>) -> Result<Option<String>, String> {
+    match path {
+        Some(path) => read_file(Path::new(path)).map(Some),
+        None if Path::new(CONFIG_FILE).exists() => read_file(Path::new(CONFIG_FILE)).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn load_config(path: Option<&String>) -> Result<BTreeMap<u8, Vec<(String, String)>>, String> {
+    match read_config(path)? {
+        Some(config) => params::parse_config(&config),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+fn parse_format(val: Option<&String>) -> Result<Format, String> {
+    val.ok_or_else(|| format!("missing format\n{}", USAGE))?
+        .parse()
+        .map_err(|e| format!("{}\n{}", e, USAGE))
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+}
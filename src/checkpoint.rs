@@ -0,0 +1,359 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashSet},
+    convert::TryInto,
+    fs,
+    hash::Hash,
+    io::{self, IsTerminal, Write},
+    iter::FromIterator,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// How often a running simulation saves its state by default.
+pub const INTERVAL: Duration = Duration::from_secs(30);
+
+const MAGIC: &[u8] = b"aoc-checkpoint 1\n";
+const REPORT_EVERY: Duration = Duration::from_secs(1);
+/// Steps are batched so that checking the clock costs little next to the work between checks.
+const CHECK_EVERY: Duration = Duration::from_millis(10);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    /// Where checkpoints are saved and looked for; `None` disables them.
+    pub dir: Option<PathBuf>,
+    pub interval: Duration,
+    /// Reports steps per second and an ETA on stderr.
+    pub progress: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            dir: None,
+            interval: INTERVAL,
+            progress: false,
+        }
+    }
+}
+
+thread_local! {
+    static SETTINGS: RefCell<Option<Settings>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `settings` applied to every `simulate` it calls on this thread.
+pub fn with<T, F: FnOnce() -> T>(settings: Settings, f: F) -> T {
+    let _outer = Restore(SETTINGS.with(|current| current.replace(Some(settings))));
+    f()
+}
+
+/// Puts back the settings `with` replaced, even if its closure panics.
+struct Restore(Option<Settings>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        SETTINGS.with(|current| current.replace(self.0.take()));
+    }
+}
+
+/// A value that can be written to and read back from a checkpoint.
+pub trait Encode: Sized {
+    fn encode(&self, out: &mut Vec<u8>);
+    fn decode(input: &mut &[u8]) -> Result<Self, String>;
+}
+
+/// Simulation state that is saved whole and restored in place, so it can hold things that
+/// are not themselves saved, such as an automaton's rule.
+pub trait Checkpoint {
+    fn save(&self, out: &mut Vec<u8>);
+    fn restore(&mut self, input: &mut &[u8]) -> Result<(), String>;
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+    if input.len() < len {
+        return Err("checkpoint is truncated".to_string());
+    }
+
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    Ok(head)
+}
+
+macro_rules! encode_int {
+    ($($t:ty as $w:ty),*) => {
+        $(
+            impl Encode for $t {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&(*self as $w).to_le_bytes());
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, String> {
+                    let bytes = take(input, std::mem::size_of::<$w>())?;
+                    Ok(<$w>::from_le_bytes(bytes.try_into().expect("sized")) as $t)
+                }
+            }
+        )*
+    };
+}
+
+encode_int!(
+    u32 as u32,
+    u64 as u64,
+    usize as u64,
+    i64 as i64,
+    isize as i64
+);
+
+impl<A: Encode, B: Encode> Encode for (A, B) {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.0.encode(out);
+        self.1.encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        Ok((A::decode(input)?, B::decode(input)?))
+    }
+}
+
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode(&self, out: &mut Vec<u8>) {
+        for item in self {
+            item.encode(out);
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        let items = (0..N)
+            .map(|_| T::decode(input))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        items
+            .try_into()
+            .map_err(|_| "checkpoint is corrupt".to_string())
+    }
+}
+
+fn encode_all<'a, T: Encode + 'a>(items: impl ExactSizeIterator<Item = &'a T>, out: &mut Vec<u8>) {
+    items.len().encode(out);
+    for item in items {
+        item.encode(out);
+    }
+}
+
+fn decode_all<T: Encode, C: FromIterator<T>>(input: &mut &[u8]) -> Result<C, String> {
+    let len = usize::decode(input)?;
+    (0..len).map(|_| T::decode(input)).collect()
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_all(self.iter(), out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        decode_all(input)
+    }
+}
+
+/// Encoded in order, so equal sets give equal checkpoints.
+impl<T: Encode + Eq + Hash + Ord> Encode for HashSet<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let mut items: Vec<&T> = self.iter().collect();
+        items.sort_unstable();
+        encode_all(items.into_iter(), out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        decode_all(input)
+    }
+}
+
+impl<K: Encode + Ord, V: Encode> Encode for BTreeMap<K, V> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        for (key, value) in self {
+            key.encode(out);
+            value.encode(out);
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        decode_all(input)
+    }
+}
+
+/// Where `simulate` keeps the checkpoint for `label`. The name includes a hash of the initial
+/// state and step count, so a checkpoint is only resumed by the same run.
+pub fn path(dir: &Path, label: &str, total: u64, initial: &[u8]) -> PathBuf {
+    // FNV-1a, which unlike `DefaultHasher` is stable across builds.
+    let hash = total
+        .to_le_bytes()
+        .iter()
+        .chain(initial)
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    dir.join(format!("{}-{:016x}.ckpt", label, hash))
+}
+
+/// Calls `step` for each of `total` steps. Under `with`, the state is saved to a checkpoint
+/// every `interval` and a run starts from a matching checkpoint if there is one; the
+/// checkpoint is removed once the run completes.
+pub fn simulate<S, F>(
+    label: &str,
+    unit: &str,
+    total: u64,
+    state: &mut S,
+    mut step: F,
+) -> Result<(), String>
+where
+    S: Checkpoint,
+    F: FnMut(&mut S),
+{
+    let settings = match SETTINGS.with(|settings| settings.borrow().clone()) {
+        Some(settings) => settings,
+        None => {
+            for _ in 0..total {
+                step(state);
+            }
+            return Ok(());
+        }
+    };
+
+    let path = settings.dir.as_ref().map(|dir| {
+        let mut initial = Vec::new();
+        state.save(&mut initial);
+        path(dir, label, total, &initial)
+    });
+
+    let mut done = match &path {
+        Some(path) if path.is_file() => resume(path, state)?,
+        _ => 0,
+    };
+
+    let mut progress = Progress::new(label, unit, done, total, settings.progress);
+    let mut last_save = Instant::now();
+    let mut last_check = Instant::now();
+    let mut batch = 1;
+
+    while done < total {
+        let end = (done + batch).min(total);
+        for _ in done..end {
+            step(state);
+        }
+        done = end;
+
+        let now = Instant::now();
+        if now - last_check < CHECK_EVERY {
+            batch = batch.saturating_mul(2);
+        }
+        last_check = now;
+
+        if let Some(path) = &path {
+            if now - last_save >= settings.interval && done < total {
+                save(path, done, state)?;
+                last_save = Instant::now();
+            }
+        }
+        progress.report(done, false);
+    }
+
+    progress.report(done, true);
+    if let Some(path) = &path {
+        if path.is_file() {
+            fs::remove_file(path)
+                .map_err(|e| format!("failed to remove {}: {}", path.display(), e))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn save<S: Checkpoint>(path: &Path, done: u64, state: &S) -> Result<(), String> {
+    let mut out = MAGIC.to_vec();
+    done.encode(&mut out);
+    state.save(&mut out);
+
+    // Write beside the checkpoint and rename, so an interrupted save leaves the last one intact.
+    let partial = path.with_extension("ckpt.partial");
+    fs::create_dir_all(path.parent().unwrap_or_else(|| Path::new(".")))
+        .and_then(|_| fs::write(&partial, &out))
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+fn resume<S: Checkpoint>(path: &Path, state: &mut S) -> Result<u64, String> {
+    let bytes = fs::read(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+    let corrupt = |e: String| format!("{}: {} (delete it to start over)", path.display(), e);
+
+    let mut input = bytes
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| corrupt("not a checkpoint".to_string()))?;
+    let done = u64::decode(&mut input).map_err(corrupt)?;
+    state.restore(&mut input).map_err(corrupt)?;
+    if !input.is_empty() {
+        return Err(corrupt("checkpoint has trailing data".to_string()));
+    }
+
+    Ok(done)
+}
+
+struct Progress<'a> {
+    label: &'a str,
+    unit: &'a str,
+    total: u64,
+    enabled: bool,
+    start: Instant,
+    start_done: u64,
+    last_report: Option<Instant>,
+}
+
+impl<'a> Progress<'a> {
+    fn new(label: &'a str, unit: &'a str, done: u64, total: u64, enabled: bool) -> Self {
+        Progress {
+            label,
+            unit,
+            total,
+            enabled,
+            start: Instant::now(),
+            start_done: done,
+            last_report: None,
+        }
+    }
+
+    fn report(&mut self, done: u64, last: bool) {
+        if !self.enabled
+            || (!last
+                && self
+                    .last_report
+                    .is_some_and(|at| at.elapsed() < REPORT_EVERY))
+        {
+            return;
+        }
+        self.last_report = Some(Instant::now());
+
+        let elapsed = self.start.elapsed().as_secs_f64();
+        let rate = (done - self.start_done) as f64 / elapsed.max(1e-9);
+        let eta = if rate > 0.0 {
+            format!("{:.0}s", (self.total - done) as f64 / rate)
+        } else {
+            "?".to_string()
+        };
+
+        let line = format!(
+            "{}: {}/{} {}, {:.0} {}/s, ETA {}",
+            self.label, done, self.total, self.unit, rate, self.unit, eta
+        );
+
+        // Redraw one line on a terminal; elsewhere, such as a log file, append lines.
+        let mut stderr = io::stderr().lock();
+        let _ = if !stderr.is_terminal() {
+            writeln!(stderr, "{}", line)
+        } else if last {
+            writeln!(stderr, "\r{}\x1b[K", line)
+        } else {
+            write!(stderr, "\r{}\x1b[K", line)
+        };
+    }
+}
@@ -0,0 +1,343 @@
+use std::{
+    env, fmt, fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use toml::Value;
+
+use crate::{
+    inputs::{self, CACHE_DIR},
+    verify::KnownAnswers,
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com/2020";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const USER_AGENT: &str = "adventofcode-2020 input fetcher";
+
+/// Minimum delay between requests, shared across runs through a marker file in the cache.
+pub const THROTTLE: Duration = Duration::from_secs(5);
+const THROTTLE_FILE: &str = ".last-request";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub trait Http {
+    fn get(&mut self, url: &str, session: &str) -> Result<Response, String>;
+    fn post(&mut self, url: &str, session: &str, form: &[(&str, &str)])
+        -> Result<Response, String>;
+}
+
+/// Sends requests through the `curl` executable. The session cookie is passed on stdin so
+/// that it never shows up in the process list.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Curl;
+
+impl Curl {
+    fn request(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let mut command = Command::new("curl");
+        command.args([
+            "--silent",
+            "--show-error",
+            "--header",
+            "@-",
+            "--user-agent",
+            USER_AGENT,
+            "--write-out",
+            "\\n%{http_code}",
+        ]);
+        for (key, value) in form {
+            command
+                .arg("--data-urlencode")
+                .arg(format!("{}={}", key, value));
+        }
+
+        let mut child = command
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(format!("Cookie: session={}\n", session).as_bytes())
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("failed to run curl: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "request to {} failed: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let stdout = String::from_utf8(output.stdout)
+            .map_err(|_| format!("response from {} is not UTF-8", url))?;
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| format!("no status from {}", url))?;
+
+        Ok(Response {
+            status: status
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid status from {}: {}", url, status))?,
+            body: body.to_string(),
+        })
+    }
+}
+
+impl Http for Curl {
+    fn get(&mut self, url: &str, session: &str) -> Result<Response, String> {
+        self.request(url, session, &[])
+    }
+
+    fn post(
+        &mut self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String> {
+        self.request(url, session, form)
+    }
+}
+
+/// Reads `session = "..."` from an `aoc.toml` file, falling back to `$AOC_SESSION`.
+pub fn session(config: Option<&str>) -> Result<String, String> {
+    if let Some(config) = config {
+        let root: Value = config
+            .parse()
+            .map_err(|e| format!("invalid config file: {}", e))?;
+
+        match root.get("session") {
+            Some(Value::String(session)) => return Ok(session.trim().to_string()),
+            Some(_) => return Err("invalid config file: `session` must be a string".to_string()),
+            None => {}
+        }
+    }
+
+    env::var(SESSION_VAR)
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| {
+            format!(
+                "no session token: set `session` in aoc.toml or ${}",
+                SESSION_VAR
+            )
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Carries the site's hint, such as "too high", when it gives one.
+    Incorrect(Option<String>),
+    TooSoon(Option<String>),
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect(None) => write!(f, "incorrect"),
+            Verdict::Incorrect(Some(hint)) => write!(f, "incorrect ({})", hint),
+            Verdict::TooSoon(None) => write!(f, "too soon"),
+            Verdict::TooSoon(Some(wait)) => write!(f, "too soon ({} left to wait)", wait),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+pub fn parse_verdict(body: &str) -> Result<Verdict, String> {
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        let hint = ["too high", "too low"]
+            .iter()
+            .find(|hint| body.contains(*hint))
+            .map(|hint| hint.to_string());
+
+        Ok(Verdict::Incorrect(hint))
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+
+        Ok(Verdict::TooSoon(wait))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else {
+        Err("unrecognised response to submission".to_string())
+    }
+}
+
+pub struct Client<H> {
+    pub http: H,
+    pub session: String,
+    pub base_url: String,
+    pub cache_dir: PathBuf,
+    pub throttle: Duration,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, session: String) -> Self {
+        Client {
+            http,
+            session,
+            base_url: BASE_URL.to_string(),
+            cache_dir: PathBuf::from(CACHE_DIR),
+            throttle: THROTTLE,
+        }
+    }
+
+    /// Returns the path of the cached input for `day`, downloading it only if it is missing.
+    pub fn fetch(&mut self, day: u8) -> Result<PathBuf, String> {
+        let path = inputs::day_file(&self.cache_dir, day);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        self.wait_turn();
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let response = self.http.get(&url, &self.session)?;
+        self.record_request()?;
+
+        match response.status {
+            200 => {}
+            400 | 401 | 403 => return Err("the session token was rejected".to_string()),
+            404 => return Err(format!("day {} is not available yet", day)),
+            status => return Err(format!("unexpected status {} from {}", status, url)),
+        }
+
+        if let Some(problem) = inputs::check(&response.body).filter(|p| p.is_fatal()) {
+            return Err(format!(
+                "downloaded day {} input is unusable: {}",
+                day, problem
+            ));
+        }
+
+        fs::write(&path, &response.body)
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+
+        Ok(path)
+    }
+
+    /// Posts `answer`, unless `answers` shows it was already accepted or rejected, and
+    /// records the outcome there.
+    pub fn submit(
+        &mut self,
+        day: u8,
+        part: u8,
+        answer: &str,
+        answers: &mut Vec<KnownAnswers>,
+    ) -> Result<Verdict, String> {
+        if part != 1 && part != 2 {
+            return Err(format!("invalid part: {}", part));
+        }
+
+        let input = inputs::day_file(&self.cache_dir, day);
+        let index = match answers
+            .iter()
+            .position(|known| known.day == day && known.input == input)
+        {
+            Some(index) => index,
+            None => {
+                answers.push(KnownAnswers::new(day, input));
+                answers.len() - 1
+            }
+        };
+        let known = &mut answers[index];
+
+        match known.part(part) {
+            Some(accepted) if accepted == answer => {
+                return Err(format!(
+                    "day {} part {} was already accepted with {}",
+                    day, part, answer
+                ))
+            }
+            Some(accepted) => {
+                return Err(format!(
+                    "day {} part {} is already solved with {}, not {}",
+                    day, part, accepted, answer
+                ))
+            }
+            None => {}
+        }
+
+        if known
+            .rejected(part)
+            .iter()
+            .any(|rejected| rejected == answer)
+        {
+            return Err(format!(
+                "{} was already rejected for day {} part {}",
+                answer, day, part
+            ));
+        }
+
+        self.wait_turn();
+        let url = format!("{}/day/{}/answer", self.base_url, day);
+        let level = part.to_string();
+        let response = self.http.post(
+            &url,
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+        self.record_request()?;
+
+        if response.status != 200 {
+            return Err(format!(
+                "unexpected status {} from {}",
+                response.status, url
+            ));
+        }
+
+        let verdict = parse_verdict(&response.body)?;
+        match verdict {
+            Verdict::Correct => known.set_part(part, answer.to_string()),
+            Verdict::Incorrect(_) => known.reject(part, answer.to_string()),
+            Verdict::TooSoon(_) | Verdict::AlreadySolved => {}
+        }
+
+        Ok(verdict)
+    }
+
+    fn wait_turn(&self) {
+        if self.throttle.is_zero() {
+            return;
+        }
+
+        let last = fs::metadata(self.cache_dir.join(THROTTLE_FILE))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+
+        if let Some(elapsed) = last {
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+    }
+
+    fn record_request(&self) -> Result<(), String> {
+        fs::create_dir_all(&self.cache_dir)
+            .and_then(|_| fs::write(self.cache_dir.join(THROTTLE_FILE), ""))
+            .map_err(|e| format!("failed to write {}: {}", self.cache_dir.display(), e))
+    }
+}
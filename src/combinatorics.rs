@@ -0,0 +1,151 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+/// Integers that can be searched for sums, with `Default` as zero.
+pub trait Summand: Copy + Ord + Hash + Default + Add<Output = Self> + Sub<Output = Self> {
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! summand {
+    ($($t:ty),*) => {
+        $(
+            impl Summand for $t {
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+summand!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Every choice of `k` entries at distinct indices, in lexicographic order of the indices.
+pub fn combinations<T>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: if k <= items.len() {
+            Some((0..k).collect())
+        } else {
+            None
+        },
+    }
+}
+
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    /// The next combination to yield, or `None` once they have all been.
+    indices: Option<Vec<usize>>,
+}
+
+impl<'a, T> Iterator for Combinations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let items = self.items;
+        let indices = self.indices.as_mut()?;
+        let combination = indices.iter().map(|&i| &items[i]).collect();
+
+        // Bump the last index that can still move right, and pack the ones after it behind it.
+        let (n, k) = (items.len(), indices.len());
+        match (0..k).rev().find(|&i| indices[i] < n - k + i) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => self.indices = None,
+        }
+
+        Some(combination)
+    }
+}
+
+/// Each run of `len` consecutive entries with its sum, updating the sum as the window slides
+/// rather than adding the run up again.
+///
+/// # Panics
+///
+/// Panics if `len` is 0.
+pub fn windows_with_sums<T: Summand>(items: &[T], len: usize) -> impl Iterator<Item = (&[T], T)> {
+    let mut sum = items
+        .iter()
+        .take(len)
+        .fold(T::default(), |sum, &item| sum + item);
+
+    items.windows(len).enumerate().map(move |(start, window)| {
+        if start > 0 {
+            sum = sum - items[start - 1] + window[len - 1];
+        }
+        (window, sum)
+    })
+}
+
+/// The first run of at least `min_len` consecutive entries that sums to `target`, found by growing
+/// a window at its end and shrinking it at its start, which only works if no entry is negative.
+/// Of the runs ending at the same entry, which differ only by leading zeros, this is the shortest.
+pub fn range_with_sum<T: Summand>(items: &[T], target: T, min_len: usize) -> Option<&[T]> {
+    let mut start = 0;
+    let mut sum = T::default();
+
+    for (end, &item) in items.iter().enumerate() {
+        sum = sum + item;
+        while sum > target && start <= end {
+            sum = sum - items[start];
+            start += 1;
+        }
+
+        if sum == target && end + 1 - start >= min_len.max(1) {
+            while items[start] == T::default() && end - start >= min_len.max(1) {
+                start += 1;
+            }
+            return Some(&items[start..=end]);
+        }
+    }
+
+    None
+}
+
+/// The indices of `k` distinct entries summing to `target`, looking complements up in a hash map
+/// so that it takes `O(n^(k-1))` time. Of all the answers, this is the first in lexicographic
+/// order of the indices.
+pub fn k_sum<T: Summand>(items: &[T], k: usize, target: T) -> Option<Vec<usize>> {
+    match k {
+        0 => (target == T::default()).then(Vec::new),
+        1 => items
+            .iter()
+            .position(|&item| item == target)
+            .map(|i| vec![i]),
+        2 => two_sum(items, target).map(|(i, j)| vec![i, j]),
+        _ => items.iter().enumerate().find_map(|(i, &item)| {
+            let rest = k_sum(&items[i + 1..], k - 1, target.checked_sub(item)?)?;
+            Some(
+                std::iter::once(i)
+                    .chain(rest.into_iter().map(|j| i + 1 + j))
+                    .collect(),
+            )
+        }),
+    }
+}
+
+/// Scans backwards, so that the map holds the earliest later index of each value.
+fn two_sum<T: Summand>(items: &[T], target: T) -> Option<(usize, usize)> {
+    let mut later = HashMap::new();
+    let mut found = None;
+
+    for (i, &item) in items.iter().enumerate().rev() {
+        if let Some(&j) = target
+            .checked_sub(item)
+            .and_then(|complement| later.get(&complement))
+        {
+            found = Some((i, j));
+        }
+        later.insert(item, i);
+    }
+
+    found
+}
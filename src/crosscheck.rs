@@ -0,0 +1,197 @@
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use crate::{params, Answer, Solution};
+
+/// Parameters that keep the days with slow references quick to crosscheck, as `(day, key,
+/// value)`. Overrides given later take precedence.
+pub const QUICK_PARAMS: &[(u8, &str, &str)] = &[
+    (15, "part2_turns", "10000"),
+    (23, "part2_moves", "1000"),
+    (23, "cups", "100"),
+];
+
+pub fn quick_params(day: u8) -> Vec<(String, String)> {
+    QUICK_PARAMS
+        .iter()
+        .filter(|(d, _, _)| *d == day)
+        .map(|(_, key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Keeps panics on this thread out of the panic hook's report until dropped, since `catch`
+/// turns them into outcomes and shrinking can cause hundreds.
+struct Quiet(bool);
+
+impl Quiet {
+    fn new() -> Quiet {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            let report = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !QUIET.with(Cell::get) {
+                    report(info);
+                }
+            }));
+        });
+
+        Quiet(QUIET.with(|quiet| quiet.replace(true)))
+    }
+}
+
+impl Drop for Quiet {
+    fn drop(&mut self) {
+        QUIET.with(|quiet| quiet.set(self.0));
+    }
+}
+
+/// A part on which a day's solver and its reference solver gave different answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: u8,
+    /// The smallest input found that still makes the two disagree.
+    pub input: String,
+    pub solver: Result<String, String>,
+    pub reference: Result<String, String>,
+}
+
+/// The solver's and the reference's outcomes for one part.
+type Outcomes = (Result<String, String>, Result<String, String>);
+
+/// Compares each part of `S` that has a reference solver against it on `input`, shrinking the
+/// input of any disagreement. Returns `None` if `S` has no reference solver.
+pub fn crosscheck<S: Solution>(
+    input: &str,
+    overrides: &[(String, String)],
+) -> Result<Option<Vec<Disagreement>>, String> {
+    let params = params::build::<S::Params>(overrides)?;
+    let parsed = S::parse(input).map_err(|e| e.render(input))?;
+    let _quiet = Quiet::new();
+
+    let mut referenced = false;
+    let mut disagreements = Vec::new();
+    for part in 1..=2 {
+        match run::<S>(&parsed, &params, part) {
+            Some(outcomes) if disagree(&outcomes) => {
+                referenced = true;
+
+                let input = shrink::<S>(input, &params, part);
+                let (solver, reference) = S::parse(&input)
+                    .ok()
+                    .and_then(|parsed| run::<S>(&parsed, &params, part))
+                    .unwrap_or(outcomes);
+
+                disagreements.push(Disagreement {
+                    part,
+                    input,
+                    solver,
+                    reference,
+                });
+            }
+            Some(_) => referenced = true,
+            None => {}
+        }
+    }
+
+    Ok(if referenced {
+        Some(disagreements)
+    } else {
+        None
+    })
+}
+
+fn run<S: Solution>(input: &S::Input, params: &S::Params, part: u8) -> Option<Outcomes> {
+    match part {
+        1 => {
+            let reference = catch(|| S::reference1(input, params))?;
+            Some((catch(|| Some(S::part1(input, params)))?, reference))
+        }
+        _ => {
+            let reference = catch(|| S::reference2(input, params))?;
+            Some((catch(|| Some(S::part2(input, params)))?, reference))
+        }
+    }
+}
+
+/// Runs a solver, treating a panic as an error so that it still counts as an outcome.
+fn catch<A, F>(f: F) -> Option<Result<String, String>>
+where
+    A: Answer,
+    F: FnOnce() -> Option<Result<A, String>>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(outcome) => outcome.map(|answer| answer.map(|answer| answer.to_string())),
+        Err(_) => Some(Err("panicked".to_string())),
+    }
+}
+
+/// Two errors agree, whatever they say: both solvers rejected the input.
+fn disagree((solver, reference): &Outcomes) -> bool {
+    match (solver, reference) {
+        (Ok(solver), Ok(reference)) => solver != reference,
+        (Err(_), Err(_)) => false,
+        _ => true,
+    }
+}
+
+/// Removes ever smaller runs of lines, or of comma-separated items for one-line inputs, for as
+/// long as what is left still parses and still disagrees on `part`.
+fn shrink<S: Solution>(input: &str, params: &S::Params, part: u8) -> String {
+    let single_line = input.lines().count() == 1;
+    let separator = if single_line { "," } else { "\n" };
+    let join = |items: &[&str]| {
+        let mut joined = items.join(separator);
+        if input.ends_with('\n') {
+            joined.push('\n');
+        }
+        joined
+    };
+    let disagrees = |candidate: &str| {
+        S::parse(candidate)
+            .ok()
+            .and_then(|parsed| run::<S>(&parsed, params, part))
+            .is_some_and(|outcomes| disagree(&outcomes))
+    };
+
+    let mut items: Vec<&str> = if single_line {
+        input.trim_end_matches(['\r', '\n']).split(',').collect()
+    } else {
+        input.lines().collect()
+    };
+
+    let mut chunk = (items.len() / 2).max(1);
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + chunk).min(items.len());
+            let candidate: Vec<&str> = items[..start]
+                .iter()
+                .chain(&items[end..])
+                .copied()
+                .collect();
+
+            if disagrees(&join(&candidate)) {
+                items = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+
+        match (removed, chunk) {
+            (true, _) => {}
+            (false, 1) => break,
+            (false, _) => chunk /= 2,
+        }
+    }
+
+    join(&items)
+}
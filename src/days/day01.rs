@@ -0,0 +1,85 @@
+use crate::{
+    combinatorics::{combinations, k_sum},
+    params::day_params,
+    parse_field, parse_lines, ParseError, Solution,
+};
+
+day_params! {
+    target: u32 = 2020,
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type Params = Params;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+
+    fn reference1(
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Option<Result<Self::Part1, String>> {
+        Some(reference1(input, params))
+    }
+
+    fn reference2(
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Option<Result<Self::Part2, String>> {
+        Some(reference2(input, params))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    parse_lines(input, |line| parse_field(line, "an expense entry"))
+}
+
+pub fn part1(inputs: &[u32], params: &Params) -> Result<u32, String> {
+    k_sum(inputs, 2, params.target)
+        .map(|indices| indices.iter().map(|&i| inputs[i]).product())
+        .ok_or_else(|| format!("no pair sums to {}", params.target))
+}
+
+pub fn part2(inputs: &[u32], params: &Params) -> Result<u32, String> {
+    k_sum(inputs, 3, params.target)
+        .map(|indices| indices.iter().map(|&i| inputs[i]).product())
+        .ok_or_else(|| format!("no triple sums to {}", params.target))
+}
+
+/// Tries every pair of entries.
+pub fn reference1(inputs: &[u32], params: &Params) -> Result<u32, String> {
+    brute_force(inputs, 2, params.target)
+        .ok_or_else(|| format!("no pair sums to {}", params.target))
+}
+
+/// Tries every triple of entries.
+pub fn reference2(inputs: &[u32], params: &Params) -> Result<u32, String> {
+    brute_force(inputs, 3, params.target)
+        .ok_or_else(|| format!("no triple sums to {}", params.target))
+}
+
+fn brute_force(inputs: &[u32], k: usize, target: u32) -> Option<u32> {
+    combinations(inputs, k)
+        .find(|entries| {
+            entries
+                .iter()
+                .try_fold(0u32, |sum, &&entry| sum.checked_add(entry))
+                == Some(target)
+        })
+        .map(|entries| entries.into_iter().product())
+}
@@ -0,0 +1,114 @@
+use std::str::FromStr;
+
+use crate::{parse_field, parse_lines, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<PasswordEntry>;
+    type Params = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<PasswordEntry>, ParseError> {
+    parse_lines(input, PasswordEntry::from_str)
+}
+
+pub fn part1(entries: &[PasswordEntry]) -> Result<usize, String> {
+    Ok(entries
+        .iter()
+        .filter(|entry| entry.is_valid_count())
+        .count())
+}
+
+pub fn part2(entries: &[PasswordEntry]) -> Result<usize, String> {
+    Ok(entries
+        .iter()
+        .filter(|entry| entry.is_valid_position())
+        .count())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordEntry {
+    pub first: u32,
+    pub second: u32,
+    pub target: char,
+    pub password: String,
+}
+
+impl PasswordEntry {
+    pub fn is_valid_count(&self) -> bool {
+        let num_present =
+            self.password
+                .chars()
+                .fold(0, |acc, c| if c == self.target { acc + 1 } else { acc });
+
+        self.first <= num_present && num_present <= self.second
+    }
+
+    pub fn is_valid_position(&self) -> bool {
+        let indices = [self.first, self.second];
+
+        self.password.char_indices().fold(false, |acc, (i, c)| {
+            if indices.contains(&((i + 1) as _)) && c == self.target {
+                !acc
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl FromStr for PasswordEntry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const EXPECTED: &str = "a password entry like `1-3 a: abcde`";
+
+        let mut iter = s.splitn(2, ':');
+        let policy = iter.next().unwrap_or(s);
+        let password = iter
+            .next()
+            .map(str::trim)
+            .ok_or_else(|| ParseError::end(s, EXPECTED))?;
+
+        let mut iter = policy.splitn(2, ' ');
+        let count = iter.next().unwrap_or(policy);
+        let target = iter
+            .next()
+            .and_then(|c| c.chars().next())
+            .ok_or_else(|| ParseError::at(s, policy, EXPECTED))?;
+
+        let mut iter = count
+            .splitn(2, '-')
+            .map(|x| parse_field(x, "a count").map_err(|e| e.within(s, x)));
+        let first = iter
+            .next()
+            .ok_or_else(|| ParseError::at(s, count, "a range like `1-3`"))??;
+        let second = iter
+            .next()
+            .ok_or_else(|| ParseError::at(s, count, "a range like `1-3`"))??;
+
+        Ok(PasswordEntry {
+            first,
+            second,
+            target,
+            password: password.to_string(),
+        })
+    }
+}
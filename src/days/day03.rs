@@ -0,0 +1,136 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    bigint::Number,
+    grid::{self, Cell},
+    params::{day_params, List},
+    ParseError, Solution,
+};
+
+day_params! {
+    slope: Slope = Slope { right: 3, down: 1 },
+    slopes: List<Slope> = List(
+        [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|&(right, down)| Slope { right, down })
+            .collect()
+    ),
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Grid;
+    type Params = Params;
+    type Part1 = u128;
+    type Part2 = Number;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    Grid::parse(input)
+}
+
+pub fn part1(coords: &Grid, params: &Params) -> Result<u128, String> {
+    Ok(count_trees(coords, params.slope.right, params.slope.down))
+}
+
+pub fn part2(coords: &Grid, params: &Params) -> Result<Number, String> {
+    Ok(params
+        .slopes
+        .0
+        .iter()
+        .map(|slope| Number::from(count_trees(coords, slope.right, slope.down)))
+        .product())
+}
+
+/// A toboggan slope written as `right:down`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid slope: {} (expected right:down)", s);
+        let (right, down) = s.split_once(':').ok_or_else(invalid)?;
+
+        Ok(Slope {
+            right: right.trim().parse().map_err(|_| invalid())?,
+            down: down
+                .trim()
+                .parse()
+                .ok()
+                .filter(|&down| down > 0)
+                .ok_or_else(invalid)?,
+        })
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.right, self.down)
+    }
+}
+
+pub fn count_trees(coords: &Grid, right: usize, down: usize) -> u128 {
+    let mut trees = 0;
+    let mut x = 0;
+    let mut y = 0;
+
+    while y < coords.height() {
+        match coords.get_wrapping(x as isize, y as isize) {
+            Tile::Open => {}
+            Tile::Tree => {
+                trees += 1;
+            }
+        }
+        x += right;
+        y += down;
+    }
+
+    trees
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Open,
+    Tree,
+}
+
+impl Cell for Tile {
+    const EXPECTED: &'static str = "`.` or `#`";
+
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Open),
+            '#' => Some(Tile::Tree),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Open => '.',
+            Tile::Tree => '#',
+        }
+    }
+}
+
+pub type Grid = grid::Grid<Tile>;
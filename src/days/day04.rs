@@ -0,0 +1,298 @@
+use std::{collections::HashMap, str::FromStr};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{inputs, ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Option<PotentialPassport>>;
+    type Params = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Option<PotentialPassport>>, ParseError> {
+    inputs::blocks(input)
+        .map(|record| parse_record(record).map_err(|e| e.within(input, record)))
+        .collect()
+}
+
+pub fn parse_record(s: &str) -> Result<Option<PotentialPassport>, ParseError> {
+    let mut map: HashMap<&str, String> = HashMap::new();
+
+    for piece in s.split_whitespace() {
+        let mut iter = piece.splitn(2, ':');
+        let key = iter.next().unwrap_or(piece);
+        let val = iter
+            .next()
+            .map(String::from)
+            .ok_or_else(|| ParseError::at(s, piece, "a `key:value` field"))?;
+        map.insert(key, val);
+    }
+
+    Ok(PotentialPassport::from_fields(map))
+}
+
+pub fn part1(potential_passports: &[Option<PotentialPassport>]) -> Result<usize, String> {
+    Ok(potential_passports.iter().flatten().count())
+}
+
+pub fn part2(potential_passports: &[Option<PotentialPassport>]) -> Result<usize, String> {
+    Ok(potential_passports
+        .iter()
+        .flatten()
+        .map(ValidPassport::from_potential)
+        .filter_map(Result::ok)
+        .count())
+}
+
+#[derive(Debug, Clone)]
+pub struct PotentialPassport {
+    pub byr: String,
+    pub iyr: String,
+    pub eyr: String,
+    pub hgt: String,
+    pub hcl: String,
+    pub ecl: String,
+    pub pid: String,
+    pub cid: Option<String>,
+}
+
+impl PotentialPassport {
+    pub fn from_fields(mut map: HashMap<&str, String>) -> Option<Self> {
+        Some(PotentialPassport {
+            byr: map.remove("byr")?,
+            iyr: map.remove("iyr")?,
+            eyr: map.remove("eyr")?,
+            hgt: map.remove("hgt")?,
+            hcl: map.remove("hcl")?,
+            ecl: map.remove("ecl")?,
+            pid: map.remove("pid")?,
+            cid: map.remove("cid"),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum LengthUnit {
+    Cm,
+    In,
+}
+
+impl FromStr for LengthUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cm" => Ok(LengthUnit::Cm),
+            "in" => Ok(LengthUnit::In),
+            x => Err(format!("Invalid unit: {}", x)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Height {
+    pub val: u16,
+    pub unit: LengthUnit,
+}
+
+impl FromStr for Height {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref HEIGHT_RE: Regex = Regex::new(r"^(?P<value>\d+)(?P<unit>(cm|in))$").unwrap();
+        }
+
+        let caps = HEIGHT_RE
+            .captures(s)
+            .ok_or(format!("invalid height: {}", s))?;
+
+        let unit = LengthUnit::from_str(
+            caps.name("unit")
+                .ok_or(format!("missing unit: {}", s))?
+                .as_str(),
+        )?;
+
+        Ok(Height {
+            val: caps
+                .name("value")
+                .ok_or(format!("missing value: {}", s))?
+                .as_str()
+                .parse()
+                .map_err(|e| format!("invalid height value: {:?}", e))
+                .and_then(|v| match unit {
+                    LengthUnit::Cm => {
+                        if (150..=193).contains(&v) {
+                            Ok(v)
+                        } else {
+                            Err(format!("invalid cm height value: {}", v))
+                        }
+                    }
+                    LengthUnit::In => {
+                        if (59..=76).contains(&v) {
+                            Ok(v)
+                        } else {
+                            Err(format!("invalid in height value: {}", v))
+                        }
+                    }
+                })?,
+            unit,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Color(pub String);
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref COLOR_RE: Regex = Regex::new(r"^#[0-9a-f]{6}$").unwrap();
+        }
+
+        if COLOR_RE.is_match(s) {
+            Ok(Color(s.to_string()))
+        } else {
+            Err(format!("invalid color: {}", s))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum EyeColor {
+    Amb,
+    Blu,
+    Brn,
+    Gry,
+    Grn,
+    Hzl,
+    Oth,
+}
+
+impl FromStr for EyeColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use EyeColor::*;
+
+        match s {
+            "amb" => Ok(Amb),
+            "blu" => Ok(Blu),
+            "brn" => Ok(Brn),
+            "gry" => Ok(Gry),
+            "grn" => Ok(Grn),
+            "hzl" => Ok(Hzl),
+            "oth" => Ok(Oth),
+            x => Err(format!("invalid eye color: {}", x)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PassportId(pub String);
+
+impl FromStr for PassportId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref PASSPORT_ID_RE: Regex = Regex::new(r"^\d{9}$").unwrap();
+        }
+
+        if PASSPORT_ID_RE.is_match(s) {
+            Ok(PassportId(s.to_string()))
+        } else {
+            Err(format!("invalid Passport ID: {}", s))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ValidPassport {
+    pub byr: u16,
+    pub iyr: u16,
+    pub eyr: u16,
+    pub hgt: Height,
+    pub hcl: Color,
+    pub ecl: EyeColor,
+    pub pid: PassportId,
+    pub cid: Option<String>,
+}
+
+fn check_date(s: &str) -> Result<&str, String> {
+    lazy_static! {
+        static ref DATE_RE: Regex = Regex::new(r"^\d{4}$").unwrap();
+    }
+
+    if DATE_RE.is_match(s) {
+        Ok(s)
+    } else {
+        Err(format!("invalid date: {}", s))
+    }
+}
+
+impl ValidPassport {
+    pub fn from_potential(p: &PotentialPassport) -> Result<Self, String> {
+        Ok(ValidPassport {
+            byr: check_date(&p.byr)?
+                .parse()
+                .ok()
+                .and_then(|v| {
+                    if (1920..=2002).contains(&v) {
+                        Some(v)
+                    } else {
+                        None
+                    }
+                })
+                .ok_or(format!("invalid byr: {}", &p.byr))?,
+            iyr: check_date(&p.iyr)?
+                .parse()
+                .ok()
+                .and_then(|v| {
+                    if (2010..=2020).contains(&v) {
+                        Some(v)
+                    } else {
+                        None
+                    }
+                })
+                .ok_or(format!("invalid iyr: {}", &p.iyr))?,
+            eyr: check_date(&p.eyr)?
+                .parse()
+                .ok()
+                .and_then(|v| {
+                    if (2020..=2030).contains(&v) {
+                        Some(v)
+                    } else {
+                        None
+                    }
+                })
+                .ok_or(format!("invalid eyr: {}", &p.eyr))?,
+            hgt: Height::from_str(&p.hgt)?,
+            hcl: Color::from_str(&p.hcl)?,
+            ecl: EyeColor::from_str(&p.ecl)?,
+            pid: PassportId::from_str(&p.pid)?,
+            cid: p.cid.clone(),
+        })
+    }
+}
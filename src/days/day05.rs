@@ -0,0 +1,195 @@
+use std::{fmt, str::FromStr};
+
+use crate::{parse_lines, ParseError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Seat>;
+    type Params = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Seat>, ParseError> {
+    parse_lines(input, Seat::from_str)
+}
+
+pub fn part1(seats: &[Seat]) -> Result<u32, String> {
+    Ok(seats.iter().fold(0, |acc, s| acc.max(s.id())))
+}
+
+pub fn part2(seats: &[Seat]) -> Result<u32, String> {
+    let mut filled_seats = [[false; MAX_COL as usize + 1]; MAX_ROW as usize + 1];
+    for seat in seats.iter() {
+        let filled_row: &mut [bool; MAX_COL as usize + 1] = &mut filled_seats[seat.row() as usize];
+        filled_row[seat.col() as usize] = true;
+    }
+
+    let mut my_seat: Option<Seat> = None;
+
+    for i in 0..filled_seats.len() {
+        let unfilled = filled_seats[i].iter().position(|x| !x);
+
+        if let Some(unfilled) = unfilled {
+            if i > 0
+                && filled_seats[i - 1].iter().all(|x| *x)
+                && i + 1 < filled_seats.len()
+                && filled_seats[i + 1].iter().all(|x| *x)
+            {
+                my_seat = Some(Seat(i as u8, unfilled as u8));
+            }
+        }
+    }
+
+    my_seat
+        .map(|seat| seat.id())
+        .ok_or_else(|| "no empty seat found".to_string())
+}
+
+pub const MAX_ROW: u8 = 127;
+pub const MAX_COL: u8 = 7;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Seat(pub u8, pub u8);
+
+impl Seat {
+    pub fn row(&self) -> u8 {
+        self.0
+    }
+    pub fn col(&self) -> u8 {
+        self.1
+    }
+
+    pub fn id(&self) -> u32 {
+        self.0 as u32 * 8u32 + self.1 as u32
+    }
+}
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in (0..7).rev() {
+            write!(f, "{}", if self.0 >> bit & 1 == 1 { 'B' } else { 'F' })?;
+        }
+        for bit in (0..3).rev() {
+            write!(f, "{}", if self.1 >> bit & 1 == 1 { 'R' } else { 'L' })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Seat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut row_bounds = Bounds(0, MAX_ROW);
+        let mut col_bounds = Bounds(0, MAX_COL);
+        let mut len = 0;
+
+        for (i, inst) in s.char_indices() {
+            let expected = match len {
+                0..=6 => "`F` or `B`",
+                7..=9 => "`L` or `R`",
+                _ => "end of line",
+            };
+
+            match (len, inst) {
+                (0..=6, 'F') => row_bounds = row_bounds.lower(),
+                (0..=6, 'B') => row_bounds = row_bounds.upper(),
+                (7..=9, 'L') => col_bounds = col_bounds.lower(),
+                (7..=9, 'R') => col_bounds = col_bounds.upper(),
+                _ => return Err(ParseError::at(s, &s[i..i + inst.len_utf8()], expected)),
+            }
+
+            len += 1;
+        }
+
+        if len < 10 {
+            return Err(ParseError::end(s, "a 10 character boarding pass"));
+        }
+
+        let to_val = |bounds: Bounds| {
+            bounds
+                .to_val()
+                .map_err(|_| ParseError::at(s, s, "a boarding pass"))
+        };
+
+        Ok(Seat(to_val(row_bounds)?, to_val(col_bounds)?))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Bounds(u8, u8);
+
+impl Bounds {
+    fn mid(&self) -> f64 {
+        (self.1 as f64 - self.0 as f64) / 2.0
+    }
+
+    fn lower(&self) -> Bounds {
+        Bounds(self.0, self.1 - self.mid().ceil() as u8)
+    }
+
+    fn upper(&self) -> Bounds {
+        Bounds(self.0 + self.mid().ceil() as u8, self.1)
+    }
+
+    fn to_val(self) -> Result<u8, String> {
+        if self.0 == self.1 {
+            Ok(self.0)
+        } else {
+            Err(format!("bounds not unified: {:?}", &self))
+        }
+    }
+}
+
+#[test]
+fn bounds_works() {
+    assert_eq!(Bounds(0, 127).lower(), Bounds(0, 63));
+    assert_eq!(Bounds(0, 63).upper(), Bounds(32, 63));
+    assert_eq!(Bounds(32, 63).lower(), Bounds(32, 47));
+    assert_eq!(Bounds(32, 47).upper(), Bounds(40, 47));
+    assert_eq!(Bounds(40, 47).upper(), Bounds(44, 47));
+    assert_eq!(Bounds(44, 47).lower(), Bounds(44, 45));
+}
+
+#[test]
+fn seat_rejects_invalid_chars() {
+    let e = Seat::from_str("FBFBXFFRLR").unwrap_err();
+    assert_eq!((e.line, e.column, e.text.as_str()), (1, 5, "X"));
+
+    let e = Seat::from_str("FBFBBFF").unwrap_err();
+    assert_eq!((e.line, e.column), (1, 8));
+}
+
+#[test]
+fn seat_works() {
+    assert_eq!(Seat::from_str("FBFBBFFRLR").unwrap(), Seat(44, 5));
+
+    let s = Seat::from_str("BFFFBBFRRR").unwrap();
+    assert_eq!(s, Seat(70, 7));
+    assert_eq!(s.id(), 567);
+
+    let s = Seat::from_str("FFFBBBFRRR").unwrap();
+    assert_eq!(s, Seat(14, 7));
+    assert_eq!(s.id(), 119);
+
+    let s = Seat::from_str("BBFFBBFRLL").unwrap();
+    assert_eq!(s, Seat(102, 4));
+    assert_eq!(s.id(), 820);
+}
@@ -0,0 +1,80 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{inputs, parse_lines, ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<HashMap<usize, HashSet<char>>>;
+    type Params = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<HashMap<usize, HashSet<char>>>, ParseError> {
+    inputs::blocks(input)
+        .map(|group_answers| {
+            Ok(parse_lines(group_answers, |person_answers| {
+                person_answers
+                    .char_indices()
+                    .map(|(i, c)| {
+                        if c.is_ascii_lowercase() {
+                            Ok(c)
+                        } else {
+                            Err(ParseError::at(
+                                person_answers,
+                                &person_answers[i..i + c.len_utf8()],
+                                "a question letter `a`-`z`",
+                            ))
+                        }
+                    })
+                    .collect::<Result<HashSet<_>, _>>()
+            })
+            .map_err(|e| e.within(input, group_answers))?
+            .into_iter()
+            .enumerate()
+            .collect())
+        })
+        .collect()
+}
+
+pub fn part1(groups: &[HashMap<usize, HashSet<char>>]) -> Result<usize, String> {
+    Ok(groups
+        .iter()
+        .map(|person_yeses| {
+            let anyone_yeses: HashSet<&char> = person_yeses.values().flatten().collect();
+            anyone_yeses.len()
+        })
+        .sum())
+}
+
+pub fn part2(groups: &[HashMap<usize, HashSet<char>>]) -> Result<usize, String> {
+    Ok(groups
+        .iter()
+        .map(|person_yeses| {
+            let anyone_yeses: HashSet<&char> = person_yeses.values().flatten().collect();
+
+            anyone_yeses.iter().fold(0, |acc, q| {
+                if person_yeses.values().all(|answers| answers.contains(q)) {
+                    acc + 1
+                } else {
+                    acc
+                }
+            })
+        })
+        .sum())
+}
@@ -0,0 +1,179 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while},
+    character::complete::digit1,
+    combinator::map_res,
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+
+use std::{fmt, str::FromStr};
+
+use crate::{bigint::Number, graph::Graph, inputs, params::day_params, ParseError, Solution};
+
+day_params! {
+    bag: BagType = BagType::new("shiny", "gold"),
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Bags;
+    type Params = Params;
+    type Part1 = usize;
+    type Part2 = Number;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Bags, ParseError> {
+    let mut bags = Bags::new();
+
+    for line in inputs::lines(input) {
+        let rule = BagRule::from_str(line).map_err(|e| e.within(input, line))?;
+
+        bags.add_node(rule.bag.clone());
+        for (count, dest_bag) in rule.contents {
+            bags.add_edge(rule.bag.clone(), dest_bag, count);
+        }
+    }
+
+    Ok(bags)
+}
+
+pub fn part1(bags: &Bags, params: &Params) -> Result<usize, String> {
+    Ok(bags.ancestors(&params.bag).len())
+}
+
+pub fn part2(bags: &Bags, params: &Params) -> Result<Number, String> {
+    bags.count_weighted_paths(&params.bag)
+}
+
+/// Edges point from each bag to the bags it directly contains, weighted by their count.
+pub type Bags = Graph<BagType, u32>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BagType(pub String, pub String);
+
+impl BagType {
+    pub fn new(adjective: &str, color: &str) -> Self {
+        BagType(adjective.to_string(), color.to_string())
+    }
+
+    fn from_tuple(((a, _, b), _, _): BagDef<'_>) -> Self {
+        BagType::new(a, b)
+    }
+}
+
+impl fmt::Display for BagType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+
+impl FromStr for BagType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [adjective, color] => Ok(BagType::new(adjective, color)),
+            _ => Err(format!("invalid bag: {} (expected adjective and color)", s)),
+        }
+    }
+}
+
+/// One line of the puzzle input: a bag and how many of each other bag it directly contains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BagRule {
+    pub bag: BagType,
+    pub contents: Vec<(u32, BagType)>,
+}
+
+impl fmt::Display for BagRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bags contain ", self.bag)?;
+
+        if self.contents.is_empty() {
+            write!(f, "no other bags")?;
+        }
+        for (i, (count, bag)) in self.contents.iter().enumerate() {
+            let separator = if i > 0 { ", " } else { "" };
+            let plural = if *count == 1 { "" } else { "s" };
+            write!(f, "{}{} {} bag{}", separator, count, bag, plural)?;
+        }
+
+        write!(f, ".")
+    }
+}
+
+impl FromStr for BagRule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const EXPECTED: &str = "a rule like `light red bags contain 1 bright white bag.`";
+
+        let (_, rule) = input_line(s).map_err(|e| match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => ParseError::at(s, e.input, EXPECTED),
+            nom::Err::Incomplete(_) => ParseError::end(s, EXPECTED),
+        })?;
+
+        Ok(rule)
+    }
+}
+
+type BagDef<'a> = ((&'a str, &'a str, &'a str), &'a str, &'a str);
+
+fn input_line(i: &str) -> IResult<&str, BagRule> {
+    let contain = tag("contain");
+    let end = tag(".");
+
+    let (input, (src_bag, _, _, _, contents, _)) =
+        tuple((bag_def, tag(" "), contain, tag(" "), possible_bags_def, end))(i)?;
+
+    Ok((
+        input,
+        BagRule {
+            bag: BagType::from_tuple(src_bag),
+            contents,
+        },
+    ))
+}
+
+fn bag_def(s: &str) -> IResult<&str, BagDef<'_>> {
+    let bag_type = tuple((take_while(|c| c != ' '), tag(" "), take_while(|c| c != ' ')));
+    let bag_word = alt((tag("bags"), tag("bag")));
+
+    tuple((bag_type, tag(" "), bag_word))(s)
+}
+
+fn possible_bags_def(s: &str) -> IResult<&str, Vec<(u32, BagType)>> {
+    let num = map_res(digit1, str::parse::<u32>);
+    let numbered_bag_def = tuple((num, tag(" "), bag_def));
+    let mut bags_def = separated_list1(tag(", "), numbered_bag_def);
+
+    if let Some(rest) = s.strip_prefix("no other bags") {
+        Ok((rest, Vec::new()))
+    } else {
+        let (input, defs) = bags_def(s)?;
+
+        Ok((
+            input,
+            defs.iter()
+                .map(|(num, _, b)| (*num, BagType::from_tuple(*b)))
+                .collect(),
+        ))
+    }
+}
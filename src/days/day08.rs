@@ -0,0 +1,207 @@
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use crate::{
+    parse_field, parse_lines,
+    trace::{self, Event},
+    ParseError, Solution,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = GameConsole;
+    type Params = ();
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<GameConsole, ParseError> {
+    GameConsole::new(input)
+}
+
+pub fn part1(console: &GameConsole) -> Result<i64, String> {
+    let mut console = console.clone();
+    run_and_detect_cycle(&mut console)?;
+
+    Ok(console.acc())
+}
+
+pub fn part2(console: &GameConsole) -> Result<i64, String> {
+    let mut console = console.clone();
+    console.reset();
+
+    // Only instructions on the unpatched path are worth patching, and it ends where it loops.
+    let mut visited = HashSet::new();
+    let mut patched_console = console.clone();
+    while let Some(&inst) = console.peek() {
+        let pc = patched_console.pc();
+        if !visited.insert(pc) {
+            break;
+        }
+
+        let patch = match inst {
+            Instruction::Jmp(arg) => Some(Instruction::Nop(arg)),
+            Instruction::Nop(arg) => Some(Instruction::Jmp(arg)),
+            Instruction::Acc(_) => None,
+        };
+
+        if let Some(patch) = patch {
+            patched_console.instructions[pc] = patch;
+            // A patch that jumps out before the first instruction fails like one that loops.
+            if run_and_detect_cycle(&mut patched_console) == Ok(false) {
+                trace::emit(|| {
+                    Event::new("patch")
+                        .with("line", pc + 1)
+                        .with("from", inst.to_string())
+                        .with("to", patch.to_string())
+                });
+
+                return Ok(patched_console.acc());
+            }
+        }
+
+        console.run_step()?;
+        patched_console = console.clone();
+    }
+
+    Err("no single patch terminates the program".to_string())
+}
+
+/// Runs until an instruction repeats, returning whether one did, or an error if the program
+/// jumps before its first instruction.
+pub fn run_and_detect_cycle(console: &mut GameConsole) -> Result<bool, String> {
+    let mut seen_states = HashSet::new();
+    let mut pc = console.pc();
+
+    while console.peek().is_some() {
+        seen_states.insert(pc);
+        console.run_step()?;
+        pc = console.pc();
+        if seen_states.contains(&pc) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+#[derive(Debug, Clone)]
+pub struct GameConsole {
+    pc: usize,
+    acc: i64,
+    instructions: Vec<Instruction>,
+}
+
+impl GameConsole {
+    pub fn new(program: &str) -> Result<GameConsole, ParseError> {
+        Ok(GameConsole {
+            pc: 0,
+            acc: 0,
+            instructions: parse_lines(program, Instruction::from_str)?,
+        })
+    }
+
+    pub fn reset(&mut self) {
+        self.pc = 0;
+        self.acc = 0;
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn acc(&self) -> i64 {
+        self.acc
+    }
+
+    pub fn peek(&self) -> Option<&Instruction> {
+        self.instructions.get(self.pc)
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn run_inst(&mut self, instruction: &Instruction) -> Result<(), String> {
+        match instruction {
+            Instruction::Acc(arg) => {
+                self.acc += *arg as i64;
+                self.pc += 1;
+            }
+            Instruction::Jmp(arg) => {
+                self.pc = self.pc.checked_add_signed(*arg as isize).ok_or_else(|| {
+                    format!(
+                        "`jmp {:+}` on line {} jumps before the first instruction",
+                        arg,
+                        self.pc + 1
+                    )
+                })?;
+            }
+            Instruction::Nop(_) => {
+                self.pc += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn run_step(&mut self) -> Result<(), String> {
+        match self.peek() {
+            Some(&inst) => self.run_inst(&inst),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Acc(i16),
+    Jmp(i16),
+    Nop(i16),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Acc(arg) => write!(f, "acc {:+}", arg),
+            Instruction::Jmp(arg) => write!(f, "jmp {:+}", arg),
+            Instruction::Nop(arg) => write!(f, "nop {:+}", arg),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const OPCODE: &str = "an opcode (`acc`, `jmp` or `nop`)";
+
+        let mut iter = s.split_whitespace();
+        let opcode = iter.next().ok_or_else(|| ParseError::end(s, OPCODE))?;
+        let arg = iter
+            .next()
+            .ok_or_else(|| ParseError::end(s, "a signed argument"))?;
+        let arg: i16 = parse_field(arg, "a signed argument").map_err(|e| e.within(s, arg))?;
+
+        match opcode {
+            "acc" => Ok(Instruction::Acc(arg)),
+            "jmp" => Ok(Instruction::Jmp(arg)),
+            "nop" => Ok(Instruction::Nop(arg)),
+            x => Err(ParseError::at(s, x, OPCODE)),
+        }
+    }
+}
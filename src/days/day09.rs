@@ -0,0 +1,98 @@
+use crate::{
+    combinatorics::{k_sum, range_with_sum},
+    params::day_params,
+    parse_field, parse_lines, ParseError, Solution,
+};
+
+pub const PREV_NUM_LOOKUP_LEN: usize = 25;
+
+day_params! {
+    preamble: usize = PREV_NUM_LOOKUP_LEN,
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<u64>;
+    type Params = Params;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+
+    fn reference2(
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Option<Result<Self::Part2, String>> {
+        Some(reference2(input, params))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse_lines(input, |line| parse_field(line, "a number"))
+}
+
+pub fn part1(inputs: &[u64], params: &Params) -> Result<u64, String> {
+    get_first_nonsum(inputs, params.preamble)
+        .ok_or_else(|| "every number is a valid sum".to_string())
+}
+
+pub fn part2(inputs: &[u64], params: &Params) -> Result<u64, String> {
+    find_weakness(inputs, part1(inputs, params)?)
+}
+
+pub fn find_weakness(inputs: &[u64], nonsum: u64) -> Result<u64, String> {
+    let contiguous = range_with_sum(inputs, nonsum, 2)
+        .ok_or_else(|| format!("no contiguous range sums to {}", nonsum))?;
+
+    weakness(contiguous)
+}
+
+fn weakness(contiguous: &[u64]) -> Result<u64, String> {
+    match (contiguous.iter().min(), contiguous.iter().max()) {
+        (Some(min), Some(max)) => Ok(min + max),
+        _ => Err("empty contiguous range".to_string()),
+    }
+}
+
+/// Sums every range of at least two numbers, taking the first to end, and the shortest of those.
+pub fn reference2(inputs: &[u64], params: &Params) -> Result<u64, String> {
+    let nonsum = part1(inputs, params)?;
+
+    for end in 2..=inputs.len() {
+        let mut sum = inputs[end - 1];
+        for start in (0..end - 1).rev() {
+            sum = match sum.checked_add(inputs[start]) {
+                Some(sum) => sum,
+                None => break,
+            };
+            if sum == nonsum {
+                return weakness(&inputs[start..end]);
+            }
+        }
+    }
+
+    Err(format!("no contiguous range sums to {}", nonsum))
+}
+
+pub fn get_first_nonsum(inputs: &[u64], lookup_len: usize) -> Option<u64> {
+    for (i, val) in inputs.iter().enumerate().skip(lookup_len) {
+        if k_sum(&inputs[i - lookup_len..i], 2, *val).is_none() {
+            return Some(*val);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use crate::{bigint::Number, graph::Graph, parse_field, parse_lines, ParseError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<u16>;
+    type Params = ();
+    type Part1 = u64;
+    type Part2 = Number;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<u16>, ParseError> {
+    let mut adapters_jolts: Vec<u16> =
+        parse_lines(input, |line| parse_field(line, "an adapter joltage"))?;
+    adapters_jolts.push(0);
+
+    adapters_jolts.sort_unstable();
+
+    Ok(adapters_jolts)
+}
+
+pub fn part1(adapters_jolts: &[u16]) -> Result<u64, String> {
+    let mut differences: BTreeMap<u16, u64> = BTreeMap::new();
+
+    for i in 1..adapters_jolts.len() {
+        let diff = adapters_jolts[i] - adapters_jolts[i - 1];
+        *differences.entry(diff).or_default() += 1;
+    }
+
+    // Built-in difference
+    *differences.entry(3).or_default() += 1;
+
+    Ok(differences.get(&1).copied().unwrap_or(0) * differences.get(&3).copied().unwrap_or(0))
+}
+
+pub fn part2(adapters_jolts: &[u16]) -> Result<Number, String> {
+    let mut adapters: Graph<u16> = Graph::new();
+    for (i, &from) in adapters_jolts.iter().enumerate() {
+        adapters.add_node(from);
+        for &to in adapters_jolts[i + 1..]
+            .iter()
+            .take_while(|&&to| to - from <= 3)
+        {
+            adapters.add_edge(from, to, ());
+        }
+    }
+
+    let last = *adapters_jolts
+        .last()
+        .ok_or_else(|| "no adapters".to_string())?;
+
+    adapters.count_paths(&0, &last)
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+
+use crate::{
+    automaton::Automaton,
+    grid::{self, Cell},
+    vector::Vector,
+    ParseError, Solution,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Grid;
+    type Params = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid) -> Result<usize, String> {
+    settle(grid, adjacent, 4)
+}
+
+pub fn part2(grid: &Grid) -> Result<usize, String> {
+    settle(grid, in_sight, 5)
+}
+
+const MAX_ROUNDS: usize = 1000;
+
+type Neighbourhood = fn(&Grid, (usize, usize)) -> Vec<(usize, usize)>;
+
+fn settle(grid: &Grid, neighbourhood: Neighbourhood, tolerance: usize) -> Result<usize, String> {
+    let mut automaton = Automaton::new(grid.clone(), neighbourhood, seating(tolerance));
+
+    let cycle = automaton.find_cycle(MAX_ROUNDS)?;
+    if cycle.period > 1 {
+        return Err(format!(
+            "seating never settles (repeats every {} rounds)",
+            cycle.period
+        ));
+    }
+
+    Ok(automaton
+        .space()
+        .iter()
+        .filter(|t| **t == Tile::Occupied)
+        .count())
+}
+
+fn round(grid: &Grid, neighbourhood: Neighbourhood, tolerance: usize) -> Grid {
+    let mut automaton = Automaton::new(grid.clone(), neighbourhood, seating(tolerance));
+    automaton.step();
+    automaton.into_space()
+}
+
+fn seating(tolerance: usize) -> impl Fn(Tile, &[Tile]) -> Tile {
+    move |tile, neighbours| {
+        let occupied = neighbours.iter().filter(|t| **t == Tile::Occupied).count();
+
+        match tile {
+            Tile::Empty if occupied == 0 => Tile::Occupied,
+            Tile::Occupied if occupied >= tolerance => Tile::Empty,
+            tile => tile,
+        }
+    }
+}
+
+fn adjacent(grid: &Grid, position: (usize, usize)) -> Vec<(usize, usize)> {
+    grid.neighbours(position).collect()
+}
+
+fn in_sight(grid: &Grid, (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+    Vector::<2>::moore_offsets()
+        .into_iter()
+        .filter_map(|direction| {
+            let mut radius = 1;
+            while let Some([new_x, new_y]) = (direction * radius).offset([x, y]) {
+                match grid.get(new_x, new_y)? {
+                    Tile::Occupied | Tile::Empty => return Some((new_x, new_y)),
+                    Tile::Floor => radius += 1,
+                }
+            }
+
+            None
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl Cell for Tile {
+    const EXPECTED: &'static str = "`.`, `L` or `#`";
+
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Floor),
+            'L' => Some(Tile::Empty),
+            '#' => Some(Tile::Occupied),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Floor => '.',
+            Tile::Empty => 'L',
+            Tile::Occupied => '#',
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+pub type Grid = grid::Grid<Tile>;
+
+impl Grid {
+    pub fn apply_round_adjacent(&self) -> Grid {
+        round(self, adjacent, 4)
+    }
+
+    pub fn apply_round_in_sight(&self) -> Grid {
+        round(self, in_sight, 5)
+    }
+}
@@ -0,0 +1,212 @@
+use std::{fmt, str::FromStr};
+
+use crate::{parse_field, parse_lines, vector::Vector, ParseError, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Action>;
+    type Params = ();
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Action>, ParseError> {
+    parse_lines(input, Action::from_str)
+}
+
+pub fn part1(actions: &[Action]) -> Result<i64, String> {
+    let mut ship = Ship::default();
+    for action in actions.iter() {
+        ship.take_action(action)?;
+    }
+
+    Ok(ship.position.manhattan() as i64)
+}
+
+pub fn part2(actions: &[Action]) -> Result<i64, String> {
+    let mut ship = Ship::default();
+    let mut waypoint = Waypoint::new();
+    for action in actions.iter() {
+        match action {
+            Action::Forward(val) => {
+                ship.move_to_waypoint(&waypoint, *val);
+            }
+            x => {
+                waypoint.take_action(x)?;
+            }
+        }
+    }
+
+    Ok(ship.position.manhattan() as i64)
+}
+
+pub trait Actionable {
+    fn take_action(&mut self, action: &Action) -> Result<(), String>;
+}
+
+/// The step for `North`, `South`, `East` and `West`, with north as positive `y`.
+fn compass_step(action: &Action) -> Option<Vector<2>> {
+    use Action::*;
+
+    match action {
+        North(val) => Some(Vector([0, *val as isize])),
+        South(val) => Some(Vector([0, -(*val as isize)])),
+        East(val) => Some(Vector([*val as isize, 0])),
+        West(val) => Some(Vector([-(*val as isize), 0])),
+        _ => None,
+    }
+}
+
+/// Turns `vector` for `Left` and `Right`, which only come in right angles.
+fn turn(vector: Vector<2>, action: &Action) -> Result<Option<Vector<2>>, String> {
+    let quarter_turns = |degrees: u16| {
+        if degrees.is_multiple_of(90) {
+            Ok(degrees as isize / 90)
+        } else {
+            Err(format!("cannot turn by {} degrees", degrees))
+        }
+    };
+
+    match action {
+        Action::Left(val) => Ok(Some(vector.turn_left(quarter_turns(*val)?))),
+        Action::Right(val) => Ok(Some(vector.turn_right(quarter_turns(*val)?))),
+        _ => Ok(None),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Waypoint {
+    /// Relative to the ship.
+    pub offset: Vector<2>,
+}
+
+impl Waypoint {
+    pub fn new() -> Waypoint {
+        Waypoint {
+            offset: Vector([10, 1]),
+        }
+    }
+}
+
+impl Default for Waypoint {
+    fn default() -> Self {
+        Waypoint::new()
+    }
+}
+
+impl Actionable for Waypoint {
+    fn take_action(&mut self, action: &Action) -> Result<(), String> {
+        if let Some(step) = compass_step(action) {
+            self.offset += step;
+        } else if let Some(offset) = turn(self.offset, action)? {
+            self.offset = offset;
+        } else {
+            return Err(format!("invalid waypoint action: {}", action));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ship {
+    pub position: Vector<2>,
+    /// A unit vector, starting east.
+    pub heading: Vector<2>,
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Ship {
+            position: Vector::ZERO,
+            heading: Vector([1, 0]),
+        }
+    }
+}
+
+impl Ship {
+    pub fn move_to_waypoint(&mut self, waypoint: &Waypoint, multiplier: u16) {
+        self.position += waypoint.offset * multiplier as isize;
+    }
+}
+
+impl Actionable for Ship {
+    fn take_action(&mut self, action: &Action) -> Result<(), String> {
+        if let Some(step) = compass_step(action) {
+            self.position += step;
+        } else if let Some(heading) = turn(self.heading, action)? {
+            self.heading = heading;
+        } else if let Action::Forward(val) = action {
+            self.position += self.heading * *val as isize;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    North(u16),
+    South(u16),
+    East(u16),
+    West(u16),
+    Left(u16),
+    Right(u16),
+    Forward(u16),
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Action::*;
+
+        match self {
+            North(val) => write!(f, "N{}", val),
+            South(val) => write!(f, "S{}", val),
+            East(val) => write!(f, "E{}", val),
+            West(val) => write!(f, "W{}", val),
+            Left(val) => write!(f, "L{}", val),
+            Right(val) => write!(f, "R{}", val),
+            Forward(val) => write!(f, "F{}", val),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Action::*;
+
+        const ACTION: &str = "an action (`N`, `S`, `E`, `W`, `L`, `R` or `F`)";
+
+        let action = s.chars().next().ok_or_else(|| ParseError::end(s, ACTION))?;
+        let (action_str, val) = s.split_at(action.len_utf8());
+        let val: u16 = parse_field(val, "an action value").map_err(|e| e.within(s, val))?;
+
+        match action {
+            'N' => Ok(North(val)),
+            'S' => Ok(South(val)),
+            'E' => Ok(East(val)),
+            'W' => Ok(West(val)),
+            'L' => Ok(Left(val)),
+            'R' => Ok(Right(val)),
+            'F' => Ok(Forward(val)),
+            _ => Err(ParseError::at(s, action_str, ACTION)),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    inputs,
+    modular::{crt, lcm},
+    parse_field, ParseError, Solution,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = (u32, Vec<Option<u32>>);
+    type Params = ();
+    type Part1 = u32;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+
+    fn reference1(input: &Self::Input, _: &Self::Params) -> Option<Result<Self::Part1, String>> {
+        Some(reference1(input))
+    }
+
+    fn reference2(input: &Self::Input, _: &Self::Params) -> Option<Result<Self::Part2, String>> {
+        Some(reference2(input))
+    }
+}
+
+pub fn parse(input: &str) -> Result<(u32, Vec<Option<u32>>), ParseError> {
+    let mut lines = inputs::lines(input);
+    let earliest_timestamp = lines
+        .next()
+        .ok_or_else(|| ParseError::end(input, "an earliest timestamp"))?;
+    let earliest_timestamp: u32 = parse_field(earliest_timestamp, "an earliest timestamp")
+        .map_err(|e| e.within(input, earliest_timestamp))?;
+    let bus_ids = lines
+        .next()
+        .ok_or_else(|| ParseError::end(input, "a list of bus ids"))?;
+    let bus_ids: Vec<Option<u32>> = bus_ids
+        .split(',')
+        .map(|id| match id {
+            "x" => Ok(None),
+            _ => parse_field(id, "a bus id or `x`")
+                .map(Some)
+                .map_err(|e| e.within(input, id)),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((earliest_timestamp, bus_ids))
+}
+
+pub fn part1((earliest_timestamp, bus_ids): &(u32, Vec<Option<u32>>)) -> Result<u32, String> {
+    let earliest_timestamp = *earliest_timestamp;
+    let earliest_float = earliest_timestamp as f64;
+
+    let (min_id, min_diff) = bus_ids
+        .iter()
+        .filter_map(|x| {
+            // A bus with id 0 never leaves, as in `reference1`.
+            x.filter(|&x| x > 0).map(|x| {
+                let mult = (earliest_float / x as f64).ceil();
+                (x, (mult as u32 * x) - earliest_timestamp)
+            })
+        })
+        .fold(None, |acc, (id, diff)| match acc {
+            Some((_min_id, min_diff)) => {
+                if diff < min_diff {
+                    Some((id, diff))
+                } else {
+                    acc
+                }
+            }
+            None => Some((id, diff)),
+        })
+        .ok_or_else(|| "no buses in service".to_string())?;
+
+    Ok(min_id * min_diff)
+}
+
+pub fn part2((_, bus_ids): &(u32, Vec<Option<u32>>)) -> Result<u128, String> {
+    let congruences = bus_ids
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, id)| id.map(|id| (offset as u64, id as u64)))
+        .map(|(offset, id)| {
+            if id == 0 {
+                Err("bus id 0 can never depart".to_string())
+            } else {
+                Ok(((id - offset % id) % id, id))
+            }
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let (timestamp, _) = crt(&congruences)?;
+
+    Ok(timestamp)
+}
+
+/// Waits at the stop a minute at a time until a bus leaves.
+pub fn reference1((earliest_timestamp, bus_ids): &(u32, Vec<Option<u32>>)) -> Result<u32, String> {
+    let ids: Vec<u32> = bus_ids
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|&id| id > 0)
+        .collect();
+    if ids.is_empty() {
+        return Err("no buses in service".to_string());
+    }
+
+    (0..)
+        .find_map(|wait| {
+            ids.iter()
+                .find(|&&id| (earliest_timestamp + wait) % id == 0)
+                .map(|id| id * wait)
+        })
+        .ok_or_else(|| "no bus leaves in time".to_string())
+}
+
+/// Lines the buses up one at a time, stepping by the period of those already in line.
+pub fn reference2((_, bus_ids): &(u32, Vec<Option<u32>>)) -> Result<u128, String> {
+    let mut timestamp = 0;
+    let mut period = 1;
+
+    for (offset, id) in bus_ids.iter().enumerate() {
+        let (offset, id) = match id {
+            Some(0) => return Err("bus id 0 can never depart".to_string()),
+            Some(id) => (offset as u128, *id as u128),
+            None => continue,
+        };
+
+        // After `id` steps the remainders repeat, so the bus can never line up.
+        let mut steps = 0;
+        while (timestamp + offset) % id != 0 {
+            if steps == id {
+                return Err(format!("bus {} never lines up with the others", id));
+            }
+            timestamp += period;
+            steps += 1;
+        }
+
+        period = lcm(period, id).ok_or_else(|| "timestamp overflows".to_string())?;
+    }
+
+    Ok(timestamp)
+}
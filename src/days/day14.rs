@@ -0,0 +1,279 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use crate::{parse_field, parse_lines, ParseError, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Instruction>;
+    type Params = ();
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, Instruction::from_str)
+}
+
+pub fn part1(program: &[Instruction]) -> Result<u128, String> {
+    let mut computer_v1 = ComputerV1::new();
+
+    for inst in program {
+        computer_v1.execute(inst)?;
+    }
+
+    Ok(computer_v1.memory_sum())
+}
+
+pub fn part2(program: &[Instruction]) -> Result<u128, String> {
+    let mut computer_v2 = ComputerV2::new();
+
+    for inst in program {
+        computer_v2.execute(inst)?;
+    }
+
+    Ok(computer_v2.memory_sum())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Mask(String),
+    Mem(u64, u64),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Mask(mask) => write!(f, "mask = {}", mask),
+            Instruction::Mem(addr, val) => write!(f, "mem[{}] = {}", addr, val),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("mask") {
+            Ok(Instruction::Mask(parse_mask_line(s)?.to_string()))
+        } else {
+            let (addr, val) = parse_mem_line(s)?;
+
+            Ok(Instruction::Mem(addr, val))
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ComputerV1 {
+    mask: Option<MaskV1>,
+    memory: BTreeMap<u64, u64>,
+}
+
+pub fn parse_mask_line(s: &str) -> Result<&str, ParseError> {
+    const EXPECTED: &str = "a mask line like `mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X`";
+
+    let mask = s
+        .strip_prefix("mask = ")
+        .ok_or_else(|| ParseError::at(s, s, EXPECTED))?;
+
+    if let Some((i, c)) = mask.char_indices().find(|&(_, c)| !"01X".contains(c)) {
+        return Err(ParseError::at(
+            s,
+            &mask[i..i + c.len_utf8()],
+            "`0`, `1` or `X`",
+        ));
+    }
+
+    if mask.len() != 36 {
+        return Err(ParseError::at(s, mask, "a 36 bit mask"));
+    }
+
+    Ok(mask)
+}
+
+pub fn parse_mem_line(s: &str) -> Result<(u64, u64), ParseError> {
+    lazy_static! {
+        static ref MEM_RE: Regex = Regex::new(r"^mem\[(?P<addr>\d+)\] = (?P<val>\d+)$").unwrap();
+    }
+
+    let caps = MEM_RE
+        .captures(s)
+        .ok_or_else(|| ParseError::at(s, s, "a line like `mem[8] = 11` or `mask = ...`"))?;
+    let field = |name, expected| {
+        let m = &s[caps.name(name).map_or(0..0, |m| m.range())];
+        parse_field(m, expected).map_err(|e: ParseError| e.within(s, m))
+    };
+
+    Ok((field("addr", "an address")?, field("val", "a value")?))
+}
+
+impl ComputerV1 {
+    pub fn new() -> Self {
+        ComputerV1 {
+            mask: None,
+            memory: BTreeMap::new(),
+        }
+    }
+
+    pub fn memory(&self) -> &BTreeMap<u64, u64> {
+        &self.memory
+    }
+
+    pub fn memory_sum(&self) -> u128 {
+        self.memory.values().fold(0u128, |acc, x| acc + *x as u128)
+    }
+
+    pub fn execute(&mut self, inst: &Instruction) -> Result<(), String> {
+        match inst {
+            Instruction::Mask(mask) => {
+                self.mask =
+                    Some(MaskV1::from_str(mask).map_err(|e| format!("invalid mask value: {}", e))?);
+            }
+            &Instruction::Mem(addr, val) => {
+                self.memory.insert(
+                    addr,
+                    self.mask
+                        .as_ref()
+                        .map(|mask| mask.apply(val))
+                        .unwrap_or(val),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct MaskV1 {
+    and_val: u64,
+    or_val: u64,
+}
+
+impl FromStr for MaskV1 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(MaskV1 {
+            and_val: mask_bits(s, &s.replace('X', "1"))?,
+            or_val: mask_bits(s, &s.replace('X', "0"))?,
+        })
+    }
+}
+
+fn mask_bits(s: &str, bits: &str) -> Result<u64, ParseError> {
+    if s.len() != 36 {
+        return Err(ParseError::at(s, s, "a 36 bit mask"));
+    }
+
+    u64::from_str_radix(bits, 2).map_err(|_| ParseError::at(s, s, "a mask of `0`, `1` or `X`"))
+}
+
+impl MaskV1 {
+    pub fn apply(&self, val: u64) -> u64 {
+        val & self.and_val | self.or_val
+    }
+}
+
+pub struct MaskV2 {
+    and_mask: u64,
+    or_mask: u64,
+    floating_masks: Vec<u64>,
+}
+
+impl FromStr for MaskV2 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let or_mask = mask_bits(s, &s.replace('X', "0"))?;
+        let and_mask = mask_bits(s, &s.replace('0', "1").replace('X', "0"))?;
+        let len = s.len() - 1;
+        let floating_bitmasks: Vec<u64> =
+            s.match_indices('X').map(|(i, _)| 1 << (len - i)).collect();
+        let mut floating_masks = Vec::with_capacity(2usize.pow(floating_bitmasks.len() as u32));
+
+        for i in 0..floating_masks.capacity() {
+            let mut val = 0;
+
+            for (k, bitmask) in floating_bitmasks.iter().enumerate() {
+                if (i >> k) & 1 == 1 {
+                    val |= bitmask;
+                }
+            }
+
+            floating_masks.push(val);
+        }
+
+        Ok(MaskV2 {
+            and_mask,
+            or_mask,
+            floating_masks,
+        })
+    }
+}
+
+impl MaskV2 {
+    pub fn addresses(&self, addr: u64) -> impl Iterator<Item = u64> + '_ {
+        let base = (addr | self.or_mask) & self.and_mask;
+
+        self.floating_masks.iter().map(move |m| m | base)
+    }
+}
+
+#[derive(Default)]
+pub struct ComputerV2 {
+    mask: Option<MaskV2>,
+    memory: BTreeMap<u64, u64>,
+}
+
+impl ComputerV2 {
+    pub fn new() -> Self {
+        ComputerV2 {
+            mask: None,
+            memory: BTreeMap::new(),
+        }
+    }
+
+    pub fn memory(&self) -> &BTreeMap<u64, u64> {
+        &self.memory
+    }
+
+    pub fn memory_sum(&self) -> u128 {
+        self.memory.values().fold(0u128, |acc, x| acc + *x as u128)
+    }
+
+    pub fn execute(&mut self, inst: &Instruction) -> Result<(), String> {
+        match inst {
+            Instruction::Mask(mask) => {
+                self.mask =
+                    Some(MaskV2::from_str(mask).map_err(|e| format!("invalid mask value: {}", e))?);
+            }
+            &Instruction::Mem(addr, val) => {
+                let mask = self.mask.as_ref().ok_or("No mask")?;
+
+                for masked_addr in mask.addresses(addr) {
+                    self.memory.insert(masked_addr, val);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
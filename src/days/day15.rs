@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    checkpoint::{self, Checkpoint, Encode},
+    inputs,
+    params::day_params,
+    parse_field, ParseError, Solution,
+};
+
+day_params! {
+    part1_turns: usize = 2020,
+    part2_turns: usize = 30000000,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<usize>;
+    type Params = Params;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+
+    fn reference1(
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Option<Result<Self::Part1, String>> {
+        Some(reference(input, params.part1_turns))
+    }
+
+    fn reference2(
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Option<Result<Self::Part2, String>> {
+        Some(reference(input, params.part2_turns))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = inputs::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::end(input, "a list of starting numbers"))?;
+
+    line.split(',')
+        .map(str::trim)
+        .map(|s| parse_field(s, "a starting number").map_err(|e| e.within(input, s)))
+        .collect()
+}
+
+pub fn part1(starting_nums: &[usize], params: &Params) -> Result<usize, String> {
+    play(starting_nums, params.part1_turns)
+}
+
+pub fn part2(starting_nums: &[usize], params: &Params) -> Result<usize, String> {
+    play(starting_nums, params.part2_turns)
+}
+
+pub fn play(starting_nums: &[usize], turns: usize) -> Result<usize, String> {
+    if starting_nums.is_empty() || turns == 0 {
+        return Err("nothing is spoken".to_string());
+    }
+    if turns <= starting_nums.len() {
+        return Ok(starting_nums[turns - 1]);
+    }
+
+    let (earlier, last) = starting_nums.split_at(starting_nums.len() - 1);
+    let nums: BTreeMap<usize, usize> = earlier
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (*x, i + 1))
+        .collect();
+
+    let mut game = Game {
+        turn: starting_nums.len(),
+        last_num: last[0],
+        nums,
+    };
+
+    checkpoint::simulate(
+        "day15",
+        "turns",
+        turns.saturating_sub(game.turn) as u64,
+        &mut game,
+        |game| {
+            let num = game
+                .nums
+                .get(&game.last_num)
+                .map(|last_spoken| game.turn - *last_spoken)
+                .unwrap_or(0);
+            game.nums.insert(game.last_num, game.turn);
+            game.last_num = num;
+            game.turn += 1;
+        },
+    )?;
+
+    Ok(game.last_num)
+}
+
+/// Plays every turn from the first, with the turn each number was last spoken on, or 0 for
+/// never, in a table indexed by the number.
+pub fn reference(starting_nums: &[usize], turns: usize) -> Result<usize, String> {
+    if starting_nums.is_empty() || turns == 0 {
+        return Err("nothing is spoken".to_string());
+    }
+
+    let len = turns.max(starting_nums.iter().max().unwrap_or(&0) + 1);
+    let mut last_spoken = vec![0; len];
+    let mut spoken = starting_nums[0];
+
+    for turn in 2..=turns {
+        let next = match starting_nums.get(turn - 1) {
+            Some(&num) => num,
+            None if last_spoken[spoken] == 0 => 0,
+            None => turn - 1 - last_spoken[spoken],
+        };
+        last_spoken[spoken] = turn - 1;
+        spoken = next;
+    }
+
+    Ok(spoken)
+}
+
+/// The spoken-number table: the turn on which each number was last spoken, before `turn`, on
+/// which `last_num` was spoken.
+struct Game {
+    nums: BTreeMap<usize, usize>,
+    last_num: usize,
+    turn: usize,
+}
+
+impl Checkpoint for Game {
+    fn save(&self, out: &mut Vec<u8>) {
+        self.nums.encode(out);
+        self.last_num.encode(out);
+        self.turn.encode(out);
+    }
+
+    fn restore(&mut self, input: &mut &[u8]) -> Result<(), String> {
+        self.nums = Encode::decode(input)?;
+        self.last_num = Encode::decode(input)?;
+        self.turn = Encode::decode(input)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use crate::{
+    bigint::Number,
+    graph::unique_matching,
+    inputs,
+    params::day_params,
+    parse_field,
+    trace::{self, Event},
+    ParseError, Solution,
+};
+
+day_params! {
+    prefix: String = "departure".to_string(),
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Notes;
+    type Params = Params;
+    type Part1 = u16;
+    type Part2 = Number;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Notes, ParseError> {
+    let mut section = InputSection::Rules;
+
+    let mut rules: Vec<TicketRule> = Vec::new();
+    let mut my_ticket = None;
+    let mut nearby_tickets: Vec<Ticket> = Vec::new();
+
+    for line in inputs::lines(input) {
+        match line {
+            "" => {
+                continue;
+            }
+            "your ticket:" => {
+                section = InputSection::MyTicket;
+            }
+            "nearby tickets:" => {
+                section = InputSection::NearbyTickets;
+            }
+            s => match section {
+                InputSection::Rules => {
+                    rules.push(TicketRule::from_str(s).map_err(|e| e.within(input, s))?);
+                }
+                InputSection::MyTicket => {
+                    my_ticket = Some(Ticket::from_str(s).map_err(|e| e.within(input, s))?);
+                }
+                InputSection::NearbyTickets => {
+                    nearby_tickets.push(Ticket::from_str(s).map_err(|e| e.within(input, s))?);
+                }
+            },
+        }
+    }
+
+    Ok(Notes {
+        rules,
+        my_ticket: my_ticket.ok_or_else(|| ParseError::end(input, "a `your ticket:` section"))?,
+        nearby_tickets,
+    })
+}
+
+pub fn part1(notes: &Notes) -> Result<u16, String> {
+    let (invalid_values, _) = notes.scan_nearby_tickets();
+    let ticket_scanning_error_rate: u16 = invalid_values.into_iter().sum();
+
+    Ok(ticket_scanning_error_rate)
+}
+
+pub fn part2(notes: &Notes, params: &Params) -> Result<Number, String> {
+    let my_ticket = &notes.my_ticket;
+
+    Ok(identify_columns(notes)?
+        .iter()
+        .filter_map(|(col, rule)| {
+            if rule.name.starts_with(&params.prefix) {
+                Some(*col)
+            } else {
+                None
+            }
+        })
+        .map(|col| Number::from(my_ticket.nums[col]))
+        .product())
+}
+
+pub fn identify_columns(notes: &Notes) -> Result<BTreeMap<usize, &TicketRule>, String> {
+    let rules = &notes.rules;
+    let my_ticket = &notes.my_ticket;
+    let (_, valid_tickets) = notes.scan_nearby_tickets();
+
+    let mut possible_col_rule_map: BTreeMap<usize, Vec<&TicketRule>> = BTreeMap::new();
+
+    for (i, num) in my_ticket.nums.iter().enumerate() {
+        for rule in rules {
+            if rule.matches(*num) {
+                possible_col_rule_map.entry(i).or_default().push(rule);
+            }
+        }
+    }
+
+    for ticket in &valid_tickets {
+        for (i, num) in ticket.nums.iter().enumerate() {
+            let possible_rules = possible_col_rule_map
+                .get_mut(&i)
+                .ok_or_else(|| format!("no rule matches column {}", i))?;
+            possible_rules.retain(|rule| rule.matches(*num));
+        }
+    }
+
+    let names: BTreeMap<&str, &TicketRule> = rules
+        .iter()
+        .map(|rule| (rule.name.as_str(), rule))
+        .collect();
+    let matching = unique_matching(possible_col_rule_map.into_iter().map(|(col, rules)| {
+        (
+            col,
+            rules.into_iter().map(|rule| rule.name.as_str()).collect(),
+        )
+    }))
+    .map_err(|e| format!("ticket columns are ambiguous ({})", e))?;
+
+    for (col, name) in &matching {
+        trace::emit(|| Event::new("column").with("index", *col).with("rule", *name));
+    }
+
+    Ok(matching
+        .into_iter()
+        .map(|(col, name)| (col, names[name]))
+        .collect())
+}
+
+#[derive(Debug, Clone)]
+pub struct Notes {
+    pub rules: Vec<TicketRule>,
+    pub my_ticket: Ticket,
+    pub nearby_tickets: Vec<Ticket>,
+}
+
+impl Notes {
+    pub fn scan_nearby_tickets(&self) -> (Vec<u16>, Vec<&Ticket>) {
+        let mut valid_tickets = Vec::new();
+
+        let invalid_values: Vec<_> = self
+            .nearby_tickets
+            .iter()
+            .flat_map(|ticket| {
+                let invalid_vals: Vec<_> = ticket
+                    .nums
+                    .iter()
+                    .filter(|n| !self.rules.iter().any(|rule| rule.matches(**n)))
+                    .collect();
+
+                if invalid_vals.is_empty() {
+                    valid_tickets.push(ticket);
+                }
+                invalid_vals
+            })
+            .copied()
+            .collect();
+
+        (invalid_values, valid_tickets)
+    }
+}
+
+enum InputSection {
+    Rules,
+    MyTicket,
+    NearbyTickets,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TicketRule {
+    pub name: String,
+    pub ranges: Vec<(u16, u16)>,
+}
+
+impl TicketRule {
+    pub fn matches(&self, val: u16) -> bool {
+        self.ranges
+            .iter()
+            .any(|range| range.0 <= val && val <= range.1)
+    }
+}
+
+impl fmt::Display for TicketRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.name)?;
+
+        for (i, (start, end)) in self.ranges.iter().enumerate() {
+            let separator = if i > 0 { " or " } else { "" };
+            write!(f, "{}{}-{}", separator, start, end)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for TicketRule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const RANGE: &str = "a range like `1-3`";
+
+        let mut halves = s.splitn(2, ':');
+        let name = halves.next().unwrap_or(s).to_string();
+        let ranges: Vec<_> = halves
+            .next()
+            .ok_or_else(|| ParseError::end(s, "`: <ranges>`"))?
+            .split(" or ")
+            .map(|range| {
+                let range = range.trim();
+                let mut iter = range.splitn(2, '-');
+                let start = iter.next().unwrap_or(range);
+                let end = iter.next().ok_or_else(|| ParseError::at(s, range, RANGE))?;
+
+                Ok((
+                    parse_field(start, RANGE).map_err(|e| e.within(s, start))?,
+                    parse_field(end, RANGE).map_err(|e| e.within(s, end))?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Ok(TicketRule { name, ranges })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ticket {
+    pub nums: Vec<u16>,
+}
+
+impl fmt::Display for Ticket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nums: Vec<String> = self.nums.iter().map(u16::to_string).collect();
+        write!(f, "{}", nums.join(","))
+    }
+}
+
+impl FromStr for Ticket {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Ticket {
+            nums: s
+                .split(',')
+                .map(|n| parse_field(n, "a ticket number").map_err(|e| e.within(s, n)))
+                .collect::<Result<Vec<_>, ParseError>>()?,
+        })
+    }
+}
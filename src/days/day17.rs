@@ -0,0 +1,95 @@
+use crate::{
+    automaton::{count_live, moore, Automaton, Sparse},
+    checkpoint, inputs,
+    params::day_params,
+    ParseError, Solution,
+};
+
+day_params! {
+    cycles: usize = 6,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Vec<(isize, isize)>;
+    type Params = Params;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<(isize, isize)>, ParseError> {
+    let mut active = Vec::new();
+    for (x, line) in inputs::lines(input).enumerate() {
+        for (y, c) in line.char_indices() {
+            match c {
+                '#' => active.push((x as isize, y as isize)),
+                '.' => {}
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        &line[y..y + c.len_utf8()],
+                        "`.` or `#`",
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(active)
+}
+
+pub fn part1(active: &[(isize, isize)], params: &Params) -> Result<usize, String> {
+    simulate::<3>(active, params.cycles)
+}
+
+pub fn part2(active: &[(isize, isize)], params: &Params) -> Result<usize, String> {
+    simulate::<4>(active, params.cycles)
+}
+
+fn simulate<const D: usize>(active: &[(isize, isize)], cycles: usize) -> Result<usize, String> {
+    let space: Sparse<[isize; D]> = active
+        .iter()
+        .map(|&(x, y)| {
+            let mut cube = [0; D];
+            cube[0] = x;
+            cube[1] = y;
+            cube
+        })
+        .collect();
+
+    let mut automaton = Automaton::new(
+        space,
+        |_, cube| moore(cube),
+        |active, neighbours| match count_live(neighbours) {
+            3 => true,
+            2 => active,
+            _ => false,
+        },
+    );
+    checkpoint::simulate(
+        &format!("day17-{}d", D),
+        "cycles",
+        cycles as u64,
+        &mut automaton,
+        |automaton| {
+            automaton.step();
+        },
+    )?;
+
+    Ok(automaton.space().len())
+}
@@ -0,0 +1,260 @@
+use crate::{bigint::Number, parse_lines, ParseError, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Vec<Token>>;
+    type Params = ();
+    type Part1 = Number;
+    type Part2 = Number;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Token>>, ParseError> {
+    parse_lines(input, |line| {
+        line.char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| {
+                Token::from_char(c).map_err(|_| {
+                    ParseError::at(
+                        line,
+                        &line[i..i + c.len_utf8()],
+                        "a digit, `+`, `*`, `(` or `)`",
+                    )
+                })
+            })
+            .collect()
+    })
+}
+
+pub fn part1(expressions: &[Vec<Token>]) -> Result<Number, String> {
+    let mut p1_sum = Number::default();
+
+    for tokens in expressions {
+        let mut p1_stack = Vec::new();
+
+        for token in tokens {
+            add_token(&mut p1_stack, token.clone());
+            reduce_stack_unprecedented(&mut p1_stack)?;
+        }
+
+        p1_sum = p1_sum + pop_result(p1_stack)?;
+    }
+
+    Ok(p1_sum)
+}
+
+pub fn part2(expressions: &[Vec<Token>]) -> Result<Number, String> {
+    let mut p2_sum = Number::default();
+
+    for tokens in expressions {
+        let mut p2_stack = Vec::new();
+
+        for token in tokens {
+            p2_stack.push(token.clone());
+            reduce_stack_precedented(&mut p2_stack)?;
+        }
+
+        reduce_stack_unprecedented(&mut p2_stack)?;
+
+        p2_sum = p2_sum + pop_result(p2_stack)?;
+    }
+
+    Ok(p2_sum)
+}
+
+pub fn pop_result(mut stack: Vec<Token>) -> Result<Number, String> {
+    if stack.len() != 1 {
+        return Err(format!("unreduced expression: {:?}", stack));
+    }
+
+    match stack.pop() {
+        Some(Token::Val(val)) => Ok(val),
+        t => Err(format!("unexpected token: {:?}", t)),
+    }
+}
+
+pub fn add_token(stack: &mut Vec<Token>, token: Token) {
+    if token == Token::CloseParen {
+        let mut tmp: Vec<Token> = Vec::new();
+        while let Some(token) = stack.pop() {
+            if token == Token::OpenParen {
+                for t in tmp.into_iter() {
+                    stack.push(t);
+                }
+                break;
+            } else {
+                tmp.push(token);
+            }
+        }
+    } else {
+        stack.push(token);
+    };
+}
+
+pub fn reduce_stack_precedented(stack: &mut Vec<Token>) -> Result<(), String> {
+    if stack.is_empty() {
+        return Ok(());
+    }
+
+    if stack.contains(&Token::OpenParen) {
+        if let Some(Token::CloseParen) = stack.last() {
+            stack.pop();
+
+            let mut tmp = Vec::new();
+            while let Some(t) = stack.pop() {
+                if t == Token::OpenParen {
+                    break;
+                }
+                tmp.push(t);
+            }
+
+            reduce_stack_precedented(&mut tmp)?;
+            reduce_stack_unprecedented(&mut tmp)?;
+
+            for t in tmp.into_iter().rev() {
+                stack.push(t);
+            }
+
+            reduce_stack_precedented(stack)?;
+        }
+    } else {
+        while let Some((i, _)) = stack
+            .iter()
+            .enumerate()
+            .find(|(_, t)| **t == Token::Operation(Operation::Add))
+        {
+            if i == 0 || i == stack.len() - 1 {
+                break;
+            }
+
+            let mut rest = stack.split_off(i + 2);
+            let lhs = match stack.pop() {
+                Some(Token::Val(x)) => Ok(x),
+                x => Err(format!("unexpected addition lhs: {:?}", x)),
+            }?;
+            let add = match stack.pop() {
+                Some(Token::Operation(add)) => Ok(add),
+                x => Err(format!("unexpected addition op: {:?}", x)),
+            }?;
+            let rhs = match stack.pop() {
+                Some(Token::Val(x)) => Ok(x),
+                x => Err(format!("unexpected addition rhs: {:?}", x)),
+            }?;
+
+            stack.push(Token::Val(add.perform(lhs, rhs)));
+            stack.append(&mut rest);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn reduce_stack_unprecedented(stack: &mut Vec<Token>) -> Result<(), String> {
+    if stack.is_empty() {
+        return Ok(());
+    }
+
+    let mut token = stack.pop().ok_or("stack exhaustion".to_string())?;
+
+    loop {
+        match &token {
+            Token::Val(val) => match stack.pop() {
+                Some(Token::Operation(op)) => match stack.pop() {
+                    Some(Token::Val(lhs)) => {
+                        token = Token::Val(op.perform(lhs, val.clone()));
+                    }
+                    Some(t) => {
+                        stack.push(t);
+                        stack.push(Token::Operation(op));
+                        stack.push(token);
+                        break;
+                    }
+                    None => {
+                        stack.push(Token::Operation(op));
+                        stack.push(token);
+                        break;
+                    }
+                },
+                Some(t) => {
+                    stack.push(t);
+                    stack.push(token);
+                    break;
+                }
+                None => {
+                    stack.push(token);
+                    break;
+                }
+            },
+            _ => {
+                stack.push(token);
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Val(Number),
+    Operation(Operation),
+    OpenParen,
+    CloseParen,
+}
+
+impl Token {
+    pub fn from_char(c: char) -> Result<Token, String> {
+        if let Ok(op) = Operation::from_char(c) {
+            Ok(Token::Operation(op))
+        } else if c.is_ascii_digit() {
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| format!("invalid value: {}", c))?;
+            Ok(Token::Val(Number::from(digit)))
+        } else {
+            match c {
+                '(' => Ok(Token::OpenParen),
+                ')' => Ok(Token::CloseParen),
+                c => Err(format!("unknown token: {}", c)),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Mul,
+}
+
+impl Operation {
+    pub fn from_char(c: char) -> Result<Operation, String> {
+        match c {
+            '+' => Ok(Operation::Add),
+            '*' => Ok(Operation::Mul),
+            c => Err(format!("invalid operation: {}", c)),
+        }
+    }
+
+    pub fn perform(&self, lhs: Number, rhs: Number) -> Number {
+        match self {
+            Operation::Add => lhs + rhs,
+            Operation::Mul => lhs * rhs,
+        }
+    }
+}
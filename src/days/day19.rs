@@ -0,0 +1,260 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use crate::{inputs, parse_field, ParseError, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = (Rules, Vec<String>);
+    type Params = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Rules, Vec<String>), ParseError> {
+    let mut rules = Rules(BTreeMap::new());
+    let mut section = Section::Rules;
+    let mut messages = Vec::new();
+
+    for line in inputs::lines(input) {
+        if line.is_empty() {
+            section = Section::Messages;
+            continue;
+        }
+
+        match section {
+            Section::Rules => {
+                let mut iter = line.splitn(2, ": ");
+                let id = iter.next().unwrap_or(line);
+                let id: u16 = parse_field(id, "a rule id").map_err(|e| e.within(input, id))?;
+                let rule = iter
+                    .next()
+                    .ok_or_else(|| ParseError::end(line, "`: <rule>`").within(input, line))?;
+                rules
+                    .0
+                    .insert(id, Rule::from_str(rule).map_err(|e| e.within(input, rule))?);
+            }
+            Section::Messages => {
+                messages.push(line.to_string());
+            }
+        }
+    }
+
+    Ok((rules, messages))
+}
+
+pub fn part1((rules, messages): &(Rules, Vec<String>)) -> Result<usize, String> {
+    count_matching(rules, messages)
+}
+
+pub fn part2((rules, messages): &(Rules, Vec<String>)) -> Result<usize, String> {
+    let mut rules = rules.clone();
+
+    rules
+        .0
+        .insert(8, Rule::Indirect(vec![vec![42], vec![42, 8]]));
+    rules
+        .0
+        .insert(11, Rule::Indirect(vec![vec![42, 31], vec![42, 11, 31]]));
+
+    count_matching(&rules, messages)
+}
+
+pub fn count_matching(rules: &Rules, messages: &[String]) -> Result<usize, String> {
+    messages.iter().try_fold(0, |acc, m| {
+        Ok(if rules.matches(m)? { acc + 1 } else { acc })
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Rules(pub BTreeMap<u16, Rule>);
+
+impl Rules {
+    pub fn is_recursive(&self, rule_id: u16) -> bool {
+        self.0
+            .get(&rule_id)
+            .is_some_and(|rule| rule.is_recursive(rule_id))
+    }
+    pub fn matches(&self, message: &str) -> Result<bool, String> {
+        let chars: Vec<char> = message.chars().collect();
+
+        let (matches, remaining) = self.rule_matches(0, chars.as_slice())?;
+
+        Ok(matches && remaining.is_empty())
+    }
+
+    fn rule_matches<'c>(
+        &self,
+        rule_id: u16,
+        chars: &'c [char],
+    ) -> Result<(bool, &'c [char]), String> {
+        if chars.is_empty() {
+            return Ok((false, chars));
+        }
+
+        let rule = self
+            .0
+            .get(&rule_id)
+            .ok_or_else(|| format!("no rule {}", rule_id))?;
+
+        match rule {
+            Rule::Direct(c) => {
+                if *c == chars[0] {
+                    Ok((true, &chars[1..]))
+                } else {
+                    Ok((false, chars))
+                }
+            }
+            Rule::Indirect(subrules) => {
+                let subrule_matches: Vec<&[char]> = subrules
+                    .iter()
+                    .filter_map(|subrule| {
+                        let mut remaining = chars;
+
+                        let mut i = 0;
+                        'subrule_id_loop: while i < subrule.len() {
+                            let subrule_id = subrule[i];
+                            if self.is_recursive(subrule_id) {
+                                if let Some(next_rule) = subrule.get(i + 1) {
+                                    for j in 1..remaining.len() {
+                                        if let Ok((matches, rest)) =
+                                            self.rule_matches(*next_rule, &remaining[j..])
+                                        {
+                                            if matches {
+                                                if let Ok((
+                                                    recursive_matches,
+                                                    recursive_remaining,
+                                                )) =
+                                                    self.rule_matches(subrule_id, &remaining[..j])
+                                                {
+                                                    if recursive_matches
+                                                        && recursive_remaining.is_empty()
+                                                    {
+                                                        remaining = rest;
+                                                        i += 2;
+                                                        continue 'subrule_id_loop;
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if let Ok((matches, rest)) = self.rule_matches(subrule_id, remaining) {
+                                if !matches {
+                                    return None;
+                                }
+                                remaining = rest;
+                            }
+
+                            i += 1;
+                        }
+
+                        Some(remaining)
+                    })
+                    .collect();
+
+                if subrule_matches.is_empty() {
+                    Ok((false, chars))
+                } else {
+                    Ok((
+                        true,
+                        subrule_matches
+                            .into_iter()
+                            .fold(None, |acc: Option<&[char]>, remaining: &[char]| {
+                                if let Some(shortest) = acc {
+                                    if remaining.len() < shortest.len() {
+                                        Some(remaining)
+                                    } else {
+                                        Some(shortest)
+                                    }
+                                } else {
+                                    Some(remaining)
+                                }
+                            })
+                            .unwrap(),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    Direct(char),
+    Indirect(Vec<Vec<u16>>),
+}
+
+impl Rule {
+    pub fn is_recursive(&self, rule_id: u16) -> bool {
+        match self {
+            Rule::Direct(_) => false,
+            Rule::Indirect(subrules) => subrules.iter().any(|subrule| subrule.contains(&rule_id)),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Direct(c) => write!(f, "\"{}\"", c),
+            Rule::Indirect(subrules) => {
+                let subrules: Vec<String> = subrules
+                    .iter()
+                    .map(|subrule| {
+                        let ids: Vec<String> = subrule.iter().map(u16::to_string).collect();
+                        ids.join(" ")
+                    })
+                    .collect();
+                write!(f, "{}", subrules.join(" | "))
+            }
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('"') {
+            Ok(Rule::Direct(s.replace('"', "").chars().next().ok_or_else(
+                || ParseError::at(s, s, "a quoted character like `\"a\"`"),
+            )?))
+        } else {
+            Ok(Rule::Indirect(
+                s.split(" | ")
+                    .map(|subrule| {
+                        subrule
+                            .split_whitespace()
+                            .map(|component| {
+                                parse_field(component, "a rule id")
+                                    .map_err(|e| e.within(s, component))
+                            })
+                            .collect::<Result<Vec<u16>, ParseError>>()
+                    })
+                    .collect::<Result<Vec<Vec<u16>>, ParseError>>()?,
+            ))
+        }
+    }
+}
+
+enum Section {
+    Rules,
+    Messages,
+}
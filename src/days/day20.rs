@@ -0,0 +1,576 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    str::FromStr,
+};
+
+use crate::{
+    bigint::Number,
+    grid::{Cell, Grid, Transform},
+    inputs, parse_field,
+    trace::{self, Event},
+    ParseError, Solution,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = BTreeMap<u16, Tile>;
+    type Params = ();
+    type Part1 = Number;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<BTreeMap<u16, Tile>, ParseError> {
+    let mut tiles: BTreeMap<u16, Tile> = BTreeMap::new();
+
+    let mut tile_id: Option<u16> = None;
+    let mut tile: Option<Tile> = None;
+    let mut i = 0;
+    for line in inputs::lines(input) {
+        if line.is_empty() {
+            if tile.is_some() {
+                return Err(ParseError::at(input, line, "a tile row"));
+            }
+
+            i = 0;
+            tile_id = None;
+            continue;
+        }
+
+        if tile_id.is_none() {
+            let id = line
+                .strip_prefix("Tile ")
+                .and_then(|s| s.strip_suffix(':'))
+                .ok_or_else(|| ParseError::at(input, line, "a tile header like `Tile 2311:`"))?;
+            tile_id = Some(parse_field(id, "a tile id").map_err(|e| e.within(input, id))?);
+            tile = Some(Tile::new());
+        } else {
+            tile.as_mut()
+                .ok_or_else(|| ParseError::at(input, line, "a blank line"))?
+                .set_row(line, i)
+                .map_err(|e| e.within(input, line))?;
+            i += 1;
+        }
+
+        if i == TILE_WIDTH {
+            if let (Some(tile_id), Some(tile)) = (tile_id, tile) {
+                tiles.insert(tile_id, tile);
+            }
+            tile = None;
+        }
+    }
+
+    if tile.is_some() {
+        return Err(ParseError::end(input, "a tile row"));
+    }
+
+    Ok(tiles)
+}
+
+pub fn part1(tiles: &BTreeMap<u16, Tile>) -> Result<Number, String> {
+    let (corners, _, _) = classify_tiles(&align_tiles(tiles));
+    for id in &corners {
+        trace::emit(|| Event::new("corner").with("tile", *id));
+    }
+
+    Ok(corners.iter().map(|&id| Number::from(id)).product())
+}
+
+pub fn part2(tiles: &BTreeMap<u16, Tile>) -> Result<usize, String> {
+    let align_map = align_tiles(tiles);
+    let (corners, edges, centers) = classify_tiles(&align_map);
+
+    let mut tiles_remaining: BTreeSet<u16> = tiles.keys().copied().collect();
+
+    let width = (tiles.len() as f64).sqrt() as usize;
+    let mut image: Vec<Vec<u16>> = vec![vec![0; width]; width];
+
+    image[0][0] = *corners
+        .iter()
+        .next()
+        .ok_or_else(|| "no corner tiles".to_string())?;
+    tiles_remaining.remove(&image[0][0]);
+
+    for x in 0..width {
+        for y in 0..width {
+            if image[x][y] != 0 {
+                continue;
+            }
+
+            let mut possible_tiles: BTreeSet<u16> = tiles_remaining
+                .intersection(
+                    if (x == 0 && y == width - 1)
+                        || (x == width - 1 && y == 0)
+                        || (x == width - 1 && y == width - 1)
+                    {
+                        &corners
+                    } else if x == 0 || x == width - 1 || y == 0 || y == width - 1 {
+                        &edges
+                    } else {
+                        &centers
+                    },
+                )
+                .copied()
+                .collect();
+
+            if x > 0 {
+                possible_tiles = possible_tiles
+                    .intersection(&align_map[&image[x - 1][y]].keys().copied().collect())
+                    .copied()
+                    .collect();
+            }
+
+            if y > 0 {
+                possible_tiles = possible_tiles
+                    .intersection(&align_map[&image[x][y - 1]].keys().copied().collect())
+                    .copied()
+                    .collect();
+            }
+
+            let tile_id = possible_tiles
+                .iter()
+                .next()
+                .copied()
+                .ok_or_else(|| format!("no tile fits at {}, {}", x, y))?;
+            tiles_remaining.remove(&tile_id);
+            image[x][y] = tile_id;
+        }
+    }
+
+    for (x, y) in [
+        (0, 0),
+        (0, width - 1),
+        (width - 1, 0),
+        (width - 1, width - 1),
+    ] {
+        trace::emit(|| {
+            Event::new("corner")
+                .with("tile", image[x][y])
+                .with("x", x)
+                .with("y", y)
+        });
+    }
+
+    let mut image: Vec<Vec<Tile>> = image
+        .iter()
+        .map(|row| row.iter().map(|id| tiles[id].clone()).collect())
+        .collect();
+
+    'outer1: loop {
+        for i in 0..8 {
+            for j in 0..8 {
+                for k in 0..8 {
+                    if (Some(Side::Bottom), Some(Side::Right))
+                        == (
+                            image[0][0].aligned_side(&image[0][1]),
+                            image[0][0].aligned_side(&image[1][0]),
+                        )
+                    {
+                        break 'outer1;
+                    }
+
+                    if k == 3 {
+                        image[1][0] = image[1][0].flip_horizontally();
+                    } else {
+                        image[1][0] = image[1][0].rotate_right();
+                    }
+                }
+                if j == 3 {
+                    image[0][1] = image[0][1].flip_horizontally();
+                } else {
+                    image[0][1] = image[0][1].rotate_right();
+                }
+            }
+            if i == 3 {
+                image[0][0] = image[0][0].flip_horizontally();
+            } else {
+                image[0][0] = image[0][0].rotate_right();
+            }
+        }
+    }
+
+    for x in 0..width {
+        for y in 0..width {
+            if matches!((x, y), (0, 0) | (1, 0) | (0, 1)) {
+                continue;
+            }
+
+            for i in 0..8 {
+                if (x == 0 || image[x][y].aligned_side(&image[x - 1][y]) == Some(Side::Left))
+                    && (y == 0 || image[x][y].aligned_side(&image[x][y - 1]) == Some(Side::Top))
+                {
+                    break;
+                }
+
+                if i == 3 {
+                    image[x][y] = image[x][y].flip_horizontally();
+                } else {
+                    image[x][y] = image[x][y].rotate_right();
+                }
+            }
+        }
+    }
+
+    let image = assemble_image(&image);
+
+    for &transform in &Transform::ALL {
+        let mut image = image.transform(transform);
+        let mut found = false;
+
+        for x in 0..(image.width() + 1).saturating_sub(SEAMONSTER_WIDTH) {
+            for y in 0..(image.height() + 1).saturating_sub(SEAMONSTER_HEIGHT) {
+                let seamonster_pixels: Vec<_> = SEAMONSTER_PIXELS
+                    .iter()
+                    .map(|&(dx, dy)| (x + dx, y + dy))
+                    .collect();
+                if seamonster_pixels.iter().all(|&pixel| image[pixel]) {
+                    found = true;
+                    for &pixel in &seamonster_pixels {
+                        image[pixel] = false;
+                    }
+                }
+            }
+        }
+
+        if found {
+            return Ok(image.iter().filter(|&&pixel| pixel).count());
+        }
+    }
+
+    Ok(image.iter().filter(|&&pixel| pixel).count())
+}
+
+pub fn align_tiles(tiles: &BTreeMap<u16, Tile>) -> BTreeMap<u16, BTreeMap<u16, Edge>> {
+    let mut align_map: BTreeMap<u16, BTreeMap<u16, Edge>> = BTreeMap::new();
+    for (id, tile) in tiles.iter() {
+        let entry = align_map.entry(*id).or_default();
+        for (other_id, other_tile) in tiles.iter() {
+            if tile != other_tile {
+                if let Some(edge) = tile.aligns_with(other_tile) {
+                    entry.insert(*other_id, edge);
+                }
+            }
+        }
+    }
+
+    align_map
+}
+
+pub fn classify_tiles(
+    align_map: &BTreeMap<u16, BTreeMap<u16, Edge>>,
+) -> (BTreeSet<u16>, BTreeSet<u16>, BTreeSet<u16>) {
+    let corners: BTreeSet<u16> = align_map
+        .iter()
+        .filter_map(|(id, touching_tiles)| {
+            if touching_tiles.len() == 2 {
+                Some(*id)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let edges: BTreeSet<u16> = align_map
+        .iter()
+        .filter_map(|(id, touching_tiles)| {
+            if touching_tiles.len() == 3 {
+                Some(*id)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let centers: BTreeSet<u16> = align_map
+        .iter()
+        .filter_map(|(id, touching_tiles)| {
+            if touching_tiles.len() == 4 {
+                Some(*id)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    (corners, edges, centers)
+}
+
+pub const TILE_WIDTH: usize = 10;
+
+pub const SEAMONSTER_WIDTH: usize = 20;
+pub const SEAMONSTER_HEIGHT: usize = 3;
+pub const SEAMONSTER_PIXELS: [(usize, usize); 15] = [
+    (0, 1),
+    (1, 2),
+    (4, 2),
+    (5, 1),
+    (6, 1),
+    (7, 2),
+    (10, 2),
+    (11, 1),
+    (12, 1),
+    (13, 2),
+    (16, 2),
+    (17, 1),
+    (18, 0),
+    (18, 1),
+    (19, 1),
+];
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Tile {
+    pub pixels: Grid<bool>,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pixels)
+    }
+}
+
+impl fmt::Debug for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n{}", self)
+    }
+}
+
+impl FromStr for Tile {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tile = Tile::new();
+
+        for (y, line) in inputs::lines(s).enumerate() {
+            tile.set_row(line, y).map_err(|e| e.within(s, line))?;
+        }
+
+        Ok(tile)
+    }
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Tile::new()
+    }
+}
+
+impl Tile {
+    pub fn new() -> Tile {
+        Tile {
+            pixels: Grid::new(TILE_WIDTH, TILE_WIDTH, false),
+        }
+    }
+
+    pub fn set_row(&mut self, row: &str, y: usize) -> Result<(), ParseError> {
+        if y >= TILE_WIDTH {
+            return Err(ParseError::at(row, row, "a blank line"));
+        }
+
+        let mut width = 0;
+        for (x, c) in row.char_indices() {
+            if width == TILE_WIDTH {
+                return Err(ParseError::at(row, &row[x..], "end of tile row"));
+            }
+
+            let pixel = Cell::from_char(c)
+                .ok_or_else(|| ParseError::at(row, &row[x..x + c.len_utf8()], bool::EXPECTED))?;
+            self.pixels.set(width, y, pixel);
+            width += 1;
+        }
+
+        if width < TILE_WIDTH {
+            return Err(ParseError::end(row, "a 10 pixel tile row"));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_row(&self, y: usize) -> Edge {
+        to_edge(self.pixels.row(y).iter())
+    }
+
+    pub fn get_col(&self, x: usize) -> Edge {
+        to_edge(self.pixels.column(x))
+    }
+
+    pub fn rotate_right(&self) -> Tile {
+        Tile {
+            pixels: self.pixels.rotate_right(),
+        }
+    }
+
+    pub fn flip_row(row: &Edge) -> Edge {
+        let mut new = *row;
+        new.reverse();
+        new
+    }
+
+    pub fn flip_horizontally(&self) -> Tile {
+        Tile {
+            pixels: self.pixels.flip_horizontally(),
+        }
+    }
+
+    pub fn edges(&self) -> [Edge; 4] {
+        [
+            self.get_edge(&Side::Top),
+            self.get_edge(&Side::Bottom),
+            self.get_edge(&Side::Left),
+            self.get_edge(&Side::Right),
+        ]
+    }
+
+    pub fn edges_match(e1: &Edge, e2: &Edge) -> bool {
+        e1 == e2 || *e1 == Tile::flip_row(e2)
+    }
+
+    pub fn aligns_with(&self, other: &Tile) -> Option<Edge> {
+        for this_edge in &self.edges() {
+            for that_edge in &other.edges() {
+                if Tile::edges_match(this_edge, that_edge) {
+                    return Some(*this_edge);
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn get_edge(&self, side: &Side) -> Edge {
+        match side {
+            Side::Top => self.get_row(0),
+            Side::Bottom => self.get_row(TILE_WIDTH - 1),
+            Side::Left => self.get_col(0),
+            Side::Right => self.get_col(TILE_WIDTH - 1),
+        }
+    }
+
+    pub fn aligned_side(&self, other: &Tile) -> Option<Side> {
+        if self.get_edge(&Side::Top) == other.get_edge(&Side::Bottom) {
+            Some(Side::Top)
+        } else if self.get_edge(&Side::Bottom) == other.get_edge(&Side::Top) {
+            Some(Side::Bottom)
+        } else if self.get_edge(&Side::Right) == other.get_edge(&Side::Left) {
+            Some(Side::Right)
+        } else if self.get_edge(&Side::Left) == other.get_edge(&Side::Right) {
+            Some(Side::Left)
+        } else {
+            None
+        }
+    }
+}
+
+pub type Edge = [bool; TILE_WIDTH];
+
+fn to_edge<'a>(pixels: impl Iterator<Item = &'a bool>) -> Edge {
+    let mut edge = [false; TILE_WIDTH];
+    for (pixel, &p) in edge.iter_mut().zip(pixels) {
+        *pixel = p;
+    }
+    edge
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Joins arranged tiles, indexed `[x][y]`, into one image without their borders.
+pub fn assemble_image(tiles: &[Vec<Tile>]) -> Grid<bool> {
+    let inner = TILE_WIDTH - 2;
+    let width = inner * tiles.len();
+
+    Grid::from_fn(width, width, |x, y| {
+        let interior = tiles[x / inner][y / inner].pixels.view(1, 1, inner, inner);
+        interior.get(x % inner, y % inner) == Some(&true)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rotate_works() {
+        assert_eq!(
+            Tile::from_str(
+                "..##.#..#.\n\
+				##..#.....\n\
+				#...##..#.\n\
+				####.#...#\n\
+				##.##.###.\n\
+				##...#.###\n\
+				.#.#.#..##\n\
+				..#....#..\n\
+				###...#.#.\n\
+				..###..###"
+            )
+            .unwrap()
+            .rotate_right(),
+            Tile::from_str(
+                ".#..#####.\n\
+				.#.####.#.\n\
+				###...#..#\n\
+				#..#.##..#\n\
+				#....#.##.\n\
+				...##.##.#\n\
+				.#...#....\n\
+				#.#.##....\n\
+				##.###.#.#\n\
+				#..##.#..."
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn flip_works() {
+        assert_eq!(
+            Tile::from_str(
+                "..##.#..#.\n\
+				##..#.....\n\
+				#...##..#.\n\
+				####.#...#\n\
+				##.##.###.\n\
+				##...#.###\n\
+				.#.#.#..##\n\
+				..#....#..\n\
+				###...#.#.\n\
+				..###..###"
+            )
+            .unwrap()
+            .flip_horizontally(),
+            Tile::from_str(
+                ".#..#.##..\n\
+				.....#..##\n\
+				.#..##...#\n\
+				#...#.####\n\
+				.###.##.##\n\
+				###.#...##\n\
+				##..#.#.#.\n\
+				..#....#..\n\
+				.#.#...###\n\
+				###..###.."
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    graph::unique_matching,
+    parse_lines,
+    trace::{self, Event},
+    ParseError, Solution,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Vec<Food>;
+    type Params = ();
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Food>, ParseError> {
+    parse_lines(input, Food::from_str)
+}
+
+pub fn part1(foods: &[Food]) -> Result<usize, String> {
+    let allergen_ingredients = identify_allergens(foods)?;
+    let claimed: HashSet<&String> = allergen_ingredients.values().copied().collect();
+
+    let mut part_1 = 0;
+    for food in foods {
+        for ingredient in &food.ingredients {
+            if !claimed.contains(ingredient) {
+                part_1 += 1;
+            }
+        }
+    }
+
+    Ok(part_1)
+}
+
+pub fn part2(foods: &[Food]) -> Result<String, String> {
+    let mut allergen_ingredients: Vec<(&str, &String)> =
+        identify_allergens(foods)?.into_iter().collect();
+    allergen_ingredients.sort_by_key(|(a, _)| *a);
+
+    Ok(allergen_ingredients
+        .into_iter()
+        .map(|(_, i)| i.as_str())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+pub fn identify_allergens(foods: &[Food]) -> Result<HashMap<&str, &String>, String> {
+    let mut allergen_map: HashMap<&str, Vec<&Food>> = HashMap::new();
+
+    for food in foods {
+        for allergen in &food.allergens {
+            allergen_map
+                .entry(allergen.as_str())
+                .or_default()
+                .push(food);
+        }
+    }
+
+    let allergen_ingredients: HashMap<&str, Vec<&String>> = allergen_map
+        .iter()
+        .map(|(allergen, foods)| {
+            let mut set: HashSet<&String> = foods[0].ingredients.iter().collect();
+            for food in foods.iter().skip(1) {
+                set = set
+                    .intersection(&food.ingredients.iter().collect())
+                    .copied()
+                    .collect();
+            }
+
+            (*allergen, set.into_iter().collect())
+        })
+        .collect();
+
+    let mut matching = unique_matching(allergen_ingredients)
+        .map_err(|e| format!("allergens are ambiguous ({})", e))?;
+    matching.sort();
+    for (allergen, ingredient) in &matching {
+        trace::emit(|| {
+            Event::new("allergen")
+                .with("allergen", *allergen)
+                .with("ingredient", ingredient.as_str())
+        });
+    }
+
+    Ok(matching.into_iter().collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Food {
+    pub ingredients: Vec<String>,
+    pub allergens: Vec<String>,
+}
+
+impl fmt::Display for Food {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (contains {})",
+            self.ingredients.join(" "),
+            self.allergens.join(", ")
+        )
+    }
+}
+
+impl FromStr for Food {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref FOOD_RE: Regex =
+                Regex::new(r"^(?P<ingredients>.+) \(contains (?P<allergens>.+)\)$").unwrap();
+        }
+
+        let caps = FOOD_RE.captures(s).ok_or_else(|| {
+            ParseError::at(s, s, "a food like `mxmxvkd kfcds (contains dairy, fish)`")
+        })?;
+
+        let ingredients = caps
+            .name("ingredients")
+            .map_or(s, |m| &s[m.range()])
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if ingredients.is_empty() {
+            return Err(ParseError::at(s, s, "a list of ingredients"));
+        }
+
+        Ok(Food {
+            ingredients,
+            allergens: caps
+                .name("allergens")
+                .ok_or_else(|| ParseError::end(s, "a list of allergens"))?
+                .as_str()
+                .split(", ")
+                .map(String::from)
+                .collect(),
+        })
+    }
+}
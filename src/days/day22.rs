@@ -0,0 +1,173 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashSet, VecDeque},
+};
+
+use crate::{inputs, parse_field, ParseError, Solution};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<VecDeque<u16>>;
+    type Params = ();
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<VecDeque<u16>>, ParseError> {
+    let mut decks: Vec<VecDeque<u16>> = Vec::new();
+    let mut seen = HashSet::new();
+
+    for line in inputs::lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with("Player") {
+            if decks.last().is_some_and(|deck| deck.is_empty()) {
+                return Err(ParseError::at(input, line, "a card"));
+            }
+            if decks.len() == 2 {
+                return Err(ParseError::at(input, line, "a card or the end of input"));
+            }
+            decks.push(VecDeque::new());
+        } else {
+            let deck = decks
+                .last_mut()
+                .ok_or_else(|| ParseError::at(input, line, "a `Player N:` header"))?;
+            let card: u16 = parse_field(line, "a card or a `Player N:` header")
+                .map_err(|e| e.within(input, line))?;
+            if !seen.insert(card) {
+                return Err(ParseError::at(input, line, "a card not already dealt"));
+            }
+            deck.push_back(card);
+        }
+    }
+
+    if decks.len() < 2 || decks.iter().any(|deck| deck.is_empty()) {
+        let expected = if decks.is_empty() {
+            "a `Player N:` header"
+        } else if decks.len() == 1 && !decks[0].is_empty() {
+            "a second `Player N:` header"
+        } else {
+            "a card"
+        };
+        return Err(ParseError::end(input, expected));
+    }
+
+    Ok(decks)
+}
+
+pub fn part1(decks: &[VecDeque<u16>]) -> Result<usize, String> {
+    if decks.len() != 2 {
+        return Err("combat needs exactly two players".to_string());
+    }
+
+    let mut combat_decks = decks.to_vec();
+
+    while combat_decks.iter().all(|deck| !deck.is_empty()) {
+        let mut played_cards: Vec<_> = combat_decks
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, deck)| deck.pop_front().map(|card| (i, card)))
+            .collect();
+
+        played_cards.sort_by_key(|&(_, card)| Reverse(card));
+
+        let player = played_cards[0].0;
+        for (_, card) in played_cards.into_iter() {
+            combat_decks[player].push_back(card);
+        }
+    }
+
+    let (_winner, winning_deck) = combat_decks
+        .iter()
+        .enumerate()
+        .find(|(_, deck)| !deck.is_empty())
+        .ok_or_else(|| "no cards dealt".to_string())?;
+
+    Ok(score(winning_deck))
+}
+
+pub fn part2(decks: &[VecDeque<u16>]) -> Result<usize, String> {
+    let mut decks = decks.to_vec();
+    let winner = play_recursive_combat(&mut decks)?;
+
+    Ok(score(&decks[winner]))
+}
+
+pub fn score(deck: &VecDeque<u16>) -> usize {
+    let mut points = 0;
+    for (i, card) in deck.iter().rev().enumerate() {
+        points += *card as usize * (i + 1);
+    }
+
+    points
+}
+
+pub fn play_recursive_combat(decks: &mut [VecDeque<u16>]) -> Result<usize, String> {
+    let mut states: HashSet<Vec<VecDeque<u16>>> = HashSet::new();
+
+    while decks.iter().all(|deck| !deck.is_empty()) {
+        if states.contains(&*decks) {
+            return Ok(0);
+        }
+        states.insert(decks.to_vec());
+
+        let mut played_cards: Vec<_> = decks
+            .iter_mut()
+            .enumerate()
+            .map(|(i, deck)| (i, deck.pop_front().unwrap()))
+            .collect();
+
+        let winner = if played_cards
+            .iter()
+            .all(|(i, card)| decks[*i].len() >= *card as usize)
+        {
+            let mut subdecks: Vec<VecDeque<u16>> = decks
+                .iter()
+                .enumerate()
+                .map(|(i, deck)| {
+                    deck.iter()
+                        .copied()
+                        .take(played_cards[i].1 as usize)
+                        .collect()
+                })
+                .collect();
+            play_recursive_combat(&mut subdecks)?
+        } else {
+            played_cards
+                .iter()
+                .fold(&(0, 0), |acc, x| if x.1 > acc.1 { x } else { acc })
+                .0
+        };
+
+        if played_cards.len() != 2 {
+            return Err("recursive combat needs exactly two players".to_string());
+        }
+        decks[winner].push_back(played_cards.remove(winner).1);
+        decks[winner].push_back(played_cards.remove(0).1);
+    }
+
+    let (winner, _winning_deck) = decks
+        .iter()
+        .enumerate()
+        .find(|(_, deck)| !deck.is_empty())
+        .ok_or_else(|| "no cards dealt".to_string())?;
+
+    Ok(winner)
+}
@@ -0,0 +1,344 @@
+use std::{collections::BTreeMap, marker::PhantomPinned, pin::Pin, ptr};
+
+use crate::{
+    checkpoint::{self, Checkpoint, Encode},
+    inputs,
+    params::day_params,
+    ParseError, Solution,
+};
+
+day_params! {
+    part1_moves: usize = 100,
+    part2_moves: usize = 10_000_000,
+    cups: usize = 1_000_000,
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Vec<usize>;
+    type Params = Params;
+    type Part1 = String;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input, params)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+
+    fn reference1(
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Option<Result<Self::Part1, String>> {
+        Some(reference1(input, params))
+    }
+
+    fn reference2(
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Option<Result<Self::Part2, String>> {
+        Some(reference2(input, params))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = inputs::lines(input).next().unwrap_or("");
+
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], "a cup label"))
+        })
+        .collect()
+}
+
+pub fn part1(input_vals: &[usize], params: &Params) -> Result<String, String> {
+    let max_val = max_label(input_vals)?;
+
+    unsafe {
+        let mut circle = create_cups(input_vals.iter().copied(), input_vals.len());
+        play(&mut circle, params.part1_moves, max_val)?;
+
+        let mut part_1 = String::new();
+        let mut cup = circle.cups.get_mut(&1).unwrap().as_ref().get_ref().next;
+        while (*cup).val != 1 {
+            part_1.push_str(&(*cup).val.to_string());
+            cup = (*cup).next;
+        }
+
+        Ok(part_1)
+    }
+}
+
+pub fn part2(input_vals: &[usize], params: &Params) -> Result<u128, String> {
+    let max_val = max_label(input_vals)?;
+    let num_cups = params.cups;
+    check_cups(num_cups, max_val)?;
+
+    unsafe {
+        let mut circle = create_cups(
+            input_vals.iter().copied().chain((max_val + 1)..=num_cups),
+            num_cups,
+        );
+        play(&mut circle, params.part2_moves, num_cups)?;
+
+        let next = circle.cups.get_mut(&1).unwrap().as_ref().get_ref().next;
+        Ok((*next).val as u128 * (*(*next).next).val as u128)
+    }
+}
+
+fn max_label(input_vals: &[usize]) -> Result<usize, String> {
+    let max_val = input_vals
+        .iter()
+        .copied()
+        .max()
+        .ok_or_else(|| "no cups".to_string())?;
+
+    if input_vals.len() < 5
+        || input_vals.len() != max_val
+        || (1..=max_val).any(|val| !input_vals.contains(&val))
+    {
+        return Err(format!("cups must be labelled 1 to {}", max_val));
+    }
+
+    Ok(max_val)
+}
+
+fn check_cups(num_cups: usize, max_val: usize) -> Result<(), String> {
+    if num_cups < max_val {
+        return Err(format!(
+            "{} cups is fewer than the highest label {}",
+            num_cups, max_val
+        ));
+    }
+
+    Ok(())
+}
+
+/// Moves the cups around a list that always starts with the current cup.
+pub fn reference1(input_vals: &[usize], params: &Params) -> Result<String, String> {
+    let max_val = max_label(input_vals)?;
+    let mut cups = input_vals.to_vec();
+
+    for _ in 0..params.part1_moves {
+        let picked_up: Vec<usize> = cups.drain(1..4).collect();
+
+        let mut dest_val = cups[0];
+        loop {
+            dest_val = if dest_val == 1 { max_val } else { dest_val - 1 };
+            if !picked_up.contains(&dest_val) {
+                break;
+            }
+        }
+
+        let dest = cups
+            .iter()
+            .position(|&cup| cup == dest_val)
+            .expect("labelled");
+        cups.splice(dest + 1..dest + 1, picked_up);
+        cups.rotate_left(1);
+    }
+
+    let one = cups.iter().position(|&cup| cup == 1).expect("labelled");
+    cups.rotate_left(one);
+
+    Ok(cups[1..].iter().map(usize::to_string).collect())
+}
+
+/// Plays with a table of the label of the cup after each label.
+pub fn reference2(input_vals: &[usize], params: &Params) -> Result<u128, String> {
+    let max_val = max_label(input_vals)?;
+    let num_cups = params.cups;
+    check_cups(num_cups, max_val)?;
+
+    let labels: Vec<usize> = input_vals
+        .iter()
+        .copied()
+        .chain((max_val + 1)..=num_cups)
+        .collect();
+    let mut next = vec![0; num_cups + 1];
+    for (i, &label) in labels.iter().enumerate() {
+        next[label] = labels[(i + 1) % labels.len()];
+    }
+
+    let mut current = labels[0];
+    for _ in 0..params.part2_moves {
+        let picked_up = [
+            next[current],
+            next[next[current]],
+            next[next[next[current]]],
+        ];
+        next[current] = next[picked_up[2]];
+
+        let mut dest_val = current;
+        loop {
+            dest_val = if dest_val == 1 {
+                num_cups
+            } else {
+                dest_val - 1
+            };
+            if !picked_up.contains(&dest_val) {
+                break;
+            }
+        }
+
+        next[picked_up[2]] = next[dest_val];
+        next[dest_val] = picked_up[0];
+        current = next[current];
+    }
+
+    Ok(next[1] as u128 * next[next[1]] as u128)
+}
+
+fn play(circle: &mut Circle, moves: usize, max_val: usize) -> Result<(), String> {
+    checkpoint::simulate("day23", "moves", moves as u64, circle, |circle| unsafe {
+        circle.current = play_round(&mut circle.cups, circle.current, max_val);
+    })
+}
+
+unsafe fn create_cups<I>(iter: I, capacity: usize) -> Circle
+where
+    I: Iterator<Item = usize>,
+{
+    let mut cups: Vec<Pin<Box<Cup>>> = Vec::with_capacity(capacity);
+
+    let mut max_val = usize::MIN;
+
+    for val in iter {
+        max_val = max_val.max(val);
+
+        let mut cup = Box::pin(Cup {
+            val,
+            next: ptr::null_mut(),
+            _pin: PhantomPinned,
+        });
+
+        if let Some(prev) = cups
+            .last_mut()
+            .map(|prev| prev.as_mut().get_unchecked_mut())
+        {
+            prev.next = cup.as_mut().get_unchecked_mut();
+        }
+
+        cups.push(cup);
+    }
+
+    let current_val = cups[0].as_ref().get_ref().val;
+
+    (cups.last_mut().unwrap().as_mut().get_unchecked_mut()).next =
+        cups.first_mut().unwrap().as_mut().get_unchecked_mut();
+
+    let mut cups: BTreeMap<usize, Pin<Box<Cup>>> =
+        cups.into_iter().map(|cup| (cup.val, cup)).collect();
+
+    let current: *mut Cup = cups
+        .get_mut(&current_val)
+        .unwrap()
+        .as_mut()
+        .get_unchecked_mut();
+
+    Circle { cups, current }
+}
+
+unsafe fn play_round(
+    cups: &mut BTreeMap<usize, Pin<Box<Cup>>>,
+    current: *mut Cup,
+    max_val: usize,
+) -> *mut Cup {
+    let picked_up_first = (*current).next;
+    let picked_up_last = (*(*picked_up_first).next).next;
+    (*current).next = (*picked_up_last).next;
+
+    let picked_up_vals = [
+        (*picked_up_first).val,
+        (*(*picked_up_first).next).val,
+        (*picked_up_last).val,
+    ];
+
+    let mut dest_val = (*current).val - 1;
+    if dest_val == 0 {
+        dest_val = max_val;
+    }
+
+    while picked_up_vals.contains(&dest_val) {
+        dest_val -= 1;
+        if dest_val == 0 {
+            dest_val = max_val;
+        }
+    }
+
+    let dest = cups
+        .get_mut(&dest_val)
+        .unwrap()
+        .as_mut()
+        .get_unchecked_mut();
+
+    (*picked_up_last).next = dest.next;
+    dest.next = picked_up_first;
+
+    (*current).next
+}
+
+/// The cups by label, each linked to the next clockwise, and the current cup.
+struct Circle {
+    cups: BTreeMap<usize, Pin<Box<Cup>>>,
+    current: *mut Cup,
+}
+
+impl Checkpoint for Circle {
+    fn save(&self, out: &mut Vec<u8>) {
+        unsafe {
+            self.cups.len().encode(out);
+            (*self.current).val.encode(out);
+            for cup in self.cups.values() {
+                (*cup.as_ref().get_ref().next).val.encode(out);
+            }
+        }
+    }
+
+    fn restore(&mut self, input: &mut &[u8]) -> Result<(), String> {
+        if usize::decode(input)? != self.cups.len() {
+            return Err("checkpoint has a different number of cups".to_string());
+        }
+
+        let cups: BTreeMap<usize, *mut Cup> = self
+            .cups
+            .iter_mut()
+            .map(|(&val, cup)| unsafe { (val, cup.as_mut().get_unchecked_mut() as *mut Cup) })
+            .collect();
+        let cup = |input: &mut &[u8]| {
+            let val = usize::decode(input)?;
+            cups.get(&val)
+                .copied()
+                .ok_or_else(|| format!("checkpoint links to a missing cup {}", val))
+        };
+
+        let current = cup(input)?;
+        for &from in cups.values() {
+            let next = cup(input)?;
+            unsafe {
+                (*from).next = next;
+            }
+        }
+        self.current = current;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Cup {
+    val: usize,
+    next: *mut Cup,
+    _pin: PhantomPinned,
+}
@@ -0,0 +1,170 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use crate::{
+    automaton::{count_live, hex_neighbours, Automaton, Sparse},
+    checkpoint,
+    params::day_params,
+    parse_lines,
+    vector::{Vector, HEX_OFFSETS},
+    ParseError, Solution,
+};
+
+day_params! {
+    days: usize = 100,
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Vec<Direction>>;
+    type Params = Params;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input, params)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Direction>>, ParseError> {
+    parse_lines(input, |line| {
+        let mut directions = Vec::new();
+
+        let mut i = 0;
+        while i < line.len() {
+            if i + 2 <= line.len() {
+                if let Some(direction) =
+                    line.get(i..i + 2).and_then(|s| Direction::from_str(s).ok())
+                {
+                    directions.push(direction);
+                    i += 2;
+                    continue;
+                }
+            }
+
+            let len = line[i..].chars().next().map_or(1, char::len_utf8);
+            let s = &line[i..i + len];
+            directions.push(Direction::from_str(s).map_err(|e| e.within(line, s))?);
+            i += len;
+        }
+
+        Ok(directions)
+    })
+}
+
+/// Prints paths one per line, as `parse` reads them.
+pub fn to_puzzle_string(paths: &[Vec<Direction>]) -> String {
+    paths
+        .iter()
+        .map(|path| path.iter().map(Direction::to_string).collect::<String>() + "\n")
+        .collect()
+}
+
+pub fn part1(paths: &[Vec<Direction>]) -> Result<usize, String> {
+    Ok(flip_tiles(paths).len())
+}
+
+pub fn part2(paths: &[Vec<Direction>], params: &Params) -> Result<usize, String> {
+    let mut automaton = Automaton::new(
+        flip_tiles(paths).into_iter().collect::<Sparse<_>>(),
+        |_, tile| hex_neighbours(tile),
+        |black, neighbours| match count_live(neighbours) {
+            1 => black,
+            2 => true,
+            _ => false,
+        },
+    );
+    checkpoint::simulate(
+        "day24",
+        "days",
+        params.days as u64,
+        &mut automaton,
+        |automaton| {
+            automaton.step();
+        },
+    )?;
+
+    Ok(automaton.space().len())
+}
+
+pub fn flip_tiles(paths: &[Vec<Direction>]) -> HashSet<(isize, isize)> {
+    let mut tiles: HashMap<(isize, isize), isize> = HashMap::new();
+    for path in paths {
+        let Vector([east, north]) = path.iter().fold(Vector::ZERO, |tile, direction| {
+            tile + direction.canonical_vector()
+        });
+
+        *tiles.entry((east, north)).or_default() += 1;
+    }
+
+    tiles
+        .into_iter()
+        .filter_map(|(c, count)| if count % 2 == 1 { Some(c) } else { None })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    East,
+    Southeast,
+    Southwest,
+    West,
+    Northwest,
+    Northeast,
+}
+
+impl Direction {
+    pub fn canonical_vector(&self) -> Vector<2> {
+        // The variants are declared in the same order as the offsets.
+        HEX_OFFSETS[*self as usize]
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Direction::East => "e",
+            Direction::Southeast => "se",
+            Direction::Southwest => "sw",
+            Direction::West => "w",
+            Direction::Northwest => "nw",
+            Direction::Northeast => "ne",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(Direction::East),
+            "se" => Ok(Direction::Southeast),
+            "sw" => Ok(Direction::Southwest),
+            "w" => Ok(Direction::West),
+            "nw" => Ok(Direction::Northwest),
+            "ne" => Ok(Direction::Northeast),
+            s => Err(ParseError::at(
+                s,
+                s,
+                "a direction (`e`, `se`, `sw`, `w`, `nw` or `ne`)",
+            )),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    inputs,
+    modular::{discrete_log, modpow},
+    parse_field, ParseError, Solution,
+};
+
+pub const INITIAL_SUBJECT_NUMBER: u64 = 7;
+pub const MODULO_NUMBER: u64 = 20201227;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = (u64, u64);
+    type Params = ();
+    type Part1 = u64;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+    let mut iter = inputs::lines(input)
+        .map(|line| parse_field(line, "a public key").map_err(|e| e.within(input, line)));
+    let door_pub_key = iter
+        .next()
+        .ok_or_else(|| ParseError::end(input, "a door public key"))??;
+    let card_pub_key = iter
+        .next()
+        .ok_or_else(|| ParseError::end(input, "a card public key"))??;
+
+    Ok((door_pub_key, card_pub_key))
+}
+
+pub fn part1(&(door_pub_key, card_pub_key): &(u64, u64)) -> Result<u64, String> {
+    let card_loop_size = find_loop_size(card_pub_key)?;
+    let door_loop_size = find_loop_size(door_pub_key)?;
+
+    let decryption_key = perform_transformation(door_pub_key, card_loop_size);
+    if decryption_key != perform_transformation(card_pub_key, door_loop_size) {
+        return Err("door and card disagree on the encryption key".to_string());
+    }
+
+    Ok(decryption_key)
+}
+
+pub fn part2(_: &(u64, u64)) -> Result<&'static str, String> {
+    Ok("Merry Christmas!")
+}
+
+pub fn find_loop_size(pub_key: u64) -> Result<u64, String> {
+    if pub_key >= MODULO_NUMBER {
+        return Err(format!("public key out of range: {}", pub_key));
+    }
+
+    discrete_log(INITIAL_SUBJECT_NUMBER, pub_key, MODULO_NUMBER)
+        .ok_or_else(|| format!("no loop size produces public key {}", pub_key))
+}
+
+pub fn perform_transformation(subject_number: u64, loop_size: u64) -> u64 {
+    modpow(subject_number, loop_size, MODULO_NUMBER)
+}
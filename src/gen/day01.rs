@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 200;
+pub const MAX_SIZE: usize = 1000;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 5..=MAX_SIZE)?;
+
+    loop {
+        let a = rng.range(100..=600) as u32;
+        let x = rng.range(200..=500) as u32;
+        let y = rng.range(200..=500) as u32;
+        let planted = [a, 2020 - a, x, y, 2020 - x - y];
+
+        let mut entries: HashSet<u32> = planted.iter().copied().collect();
+        if entries.len() < planted.len() {
+            continue;
+        }
+
+        // Fillers are over 1010, so two of them sum past 2020 and with a large planted value they
+        // do too. Only these would complete another pair or triple with the small planted values.
+        let forbidden = [2020 - x, 2020 - y, 2020 - a - x, 2020 - a - y];
+        let mut fillers: Vec<u32> = (1011..=2019)
+            .filter(|filler| !entries.contains(filler) && !forbidden.contains(filler))
+            .collect();
+        let needed = size - entries.len();
+        if fillers.len() < needed {
+            continue;
+        }
+        rng.shuffle(&mut fillers);
+        entries.extend(fillers.into_iter().take(needed));
+
+        let mut entries: Vec<u32> = entries.into_iter().collect();
+        entries.sort_unstable();
+
+        if sums(&entries, 2) != 1 || sums(&entries, 3) != 1 {
+            continue;
+        }
+
+        rng.shuffle(&mut entries);
+        let input = entries.iter().map(|x| format!("{}\n", x)).collect();
+
+        return Ok(Generated::with_answers(
+            input,
+            a * (2020 - a),
+            x * y * (2020 - x - y),
+        ));
+    }
+}
+
+fn sums(entries: &[u32], count: usize) -> usize {
+    let set: HashSet<u32> = entries.iter().copied().collect();
+
+    match count {
+        2 => entries
+            .iter()
+            .filter(|&&x| x <= 1010 && set.contains(&(2020 - x)))
+            .count(),
+        _ => entries
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &x)| entries[i + 1..].iter().map(move |&y| (x, y)))
+            .filter(|&(x, y)| {
+                x + y <= 2020 && {
+                    let z = 2020 - x - y;
+                    z > y && set.contains(&z)
+                }
+            })
+            .count(),
+    }
+}
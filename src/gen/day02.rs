@@ -0,0 +1,46 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    let mut input = String::new();
+    let mut valid_counts = 0;
+    let mut valid_positions = 0;
+
+    for _ in 0..size {
+        let low = rng.range(1..=5) as usize;
+        let high = rng.range(low as i64 + 1..=low as i64 + 8) as usize;
+        let letter = (b'a' + rng.below(26) as u8) as char;
+
+        let len = rng.range(high as i64..=high as i64 + 6) as usize;
+        let password: String = (0..len)
+            .map(|_| {
+                if rng.chance(0.3) {
+                    letter
+                } else {
+                    (b'a' + rng.below(26) as u8) as char
+                }
+            })
+            .collect();
+
+        let count = password.chars().filter(|&c| c == letter).count();
+        if (low..=high).contains(&count) {
+            valid_counts += 1;
+        }
+
+        let at = |pos: usize| password.as_bytes()[pos - 1] == letter as u8;
+        if at(low) != at(high) {
+            valid_positions += 1;
+        }
+
+        input.push_str(&format!("{}-{} {}: {}\n", low, high, letter, password));
+    }
+
+    Ok(Generated::with_answers(
+        input,
+        valid_counts,
+        valid_positions,
+    ))
+}
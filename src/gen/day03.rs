@@ -0,0 +1,37 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 323;
+
+const WIDTH: usize = 31;
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    let grid: Vec<Vec<bool>> = (0..size)
+        .map(|_| (0..WIDTH).map(|_| rng.chance(0.2)).collect())
+        .collect();
+
+    let trees = |(right, down): (usize, usize)| {
+        (0..size)
+            .step_by(down)
+            .enumerate()
+            .filter(|&(i, y)| grid[y][i * right % WIDTH])
+            .count() as u64
+    };
+
+    let input = grid
+        .iter()
+        .map(|row| {
+            let mut line: String = row.iter().map(|&t| if t { '#' } else { '.' }).collect();
+            line.push('\n');
+            line
+        })
+        .collect();
+
+    Ok(Generated::with_answers(
+        input,
+        trees((3, 1)),
+        SLOPES.iter().map(|&slope| trees(slope)).product::<u64>(),
+    ))
+}
@@ -0,0 +1,109 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 300;
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    let mut passports = Vec::new();
+    let mut complete = 0;
+    let mut valid = 0;
+
+    for _ in 0..size {
+        let missing = if rng.chance(0.25) {
+            Some(rng.below(REQUIRED.len()))
+        } else {
+            None
+        };
+        let invalid = if rng.chance(0.4) {
+            Some(rng.below(REQUIRED.len()))
+        } else {
+            None
+        };
+
+        let mut fields: Vec<String> = REQUIRED
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| missing != Some(i))
+            .map(|(i, key)| format!("{}:{}", key, field(rng, key, invalid == Some(i))))
+            .collect();
+        if rng.chance(0.5) {
+            fields.push(format!("cid:{}", rng.range(1..=350)));
+        }
+        rng.shuffle(&mut fields);
+
+        if missing.is_none() {
+            complete += 1;
+            if invalid.is_none() {
+                valid += 1;
+            }
+        }
+
+        let mut record = String::new();
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                record.push(if rng.chance(0.3) { '\n' } else { ' ' });
+            }
+            record.push_str(field);
+        }
+        passports.push(record);
+    }
+
+    Ok(Generated::with_answers(
+        passports.join("\n\n") + "\n",
+        complete,
+        valid,
+    ))
+}
+
+fn field(rng: &mut Rng, key: &str, invalid: bool) -> String {
+    let year = |rng: &mut Rng, low: i64, high: i64| {
+        if invalid {
+            if rng.chance(0.5) {
+                rng.range(low - 20..=low - 1).to_string()
+            } else {
+                rng.range(high + 1..=high + 20).to_string()
+            }
+        } else {
+            rng.range(low..=high).to_string()
+        }
+    };
+
+    match key {
+        "byr" => year(rng, 1920, 2002),
+        "iyr" => year(rng, 2010, 2020),
+        "eyr" => year(rng, 2020, 2030),
+        "hgt" => match (invalid, rng.chance(0.5)) {
+            (false, true) => format!("{}cm", rng.range(150..=193)),
+            (false, false) => format!("{}in", rng.range(59..=76)),
+            (true, true) => format!("{}cm", rng.range(194..=220)),
+            (true, false) => rng.range(59..=76).to_string(),
+        },
+        "hcl" => {
+            let hex: String = (0..6)
+                .map(|_| *rng.choose(b"0123456789abcdef") as char)
+                .collect();
+            if invalid {
+                hex
+            } else {
+                format!("#{}", hex)
+            }
+        }
+        "ecl" => {
+            if invalid {
+                rng.choose(&["red", "xyz", "gmt", "zzz"]).to_string()
+            } else {
+                rng.choose(&EYE_COLORS).to_string()
+            }
+        }
+        _ => {
+            let len = if invalid { 10 } else { 9 };
+            (0..len)
+                .map(|_| (b'0' + rng.below(10) as u8) as char)
+                .collect()
+        }
+    }
+}
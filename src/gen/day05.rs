@@ -0,0 +1,38 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 800;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 32..=1000)?;
+
+    let low = rng.range(8..=(1023 - size as i64)) as u32;
+    let high = low + size as u32;
+    let first_row = low.div_ceil(8) + 1;
+    let last_row = (high + 1) / 8 - 2;
+    let mine = rng.range(first_row as i64 * 8..=last_row as i64 * 8 + 7) as u32;
+
+    let mut seats: Vec<u32> = (low..=high).filter(|&id| id != mine).collect();
+    rng.shuffle(&mut seats);
+
+    let input = seats
+        .iter()
+        .map(|&id| {
+            let mut line: String = (0..10)
+                .rev()
+                .map(|bit| {
+                    let set = id >> bit & 1 == 1;
+                    match (bit >= 3, set) {
+                        (true, false) => 'F',
+                        (true, true) => 'B',
+                        (false, false) => 'L',
+                        (false, true) => 'R',
+                    }
+                })
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect();
+
+    Ok(Generated::with_answers(input, high, mine))
+}
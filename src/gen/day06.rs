@@ -0,0 +1,44 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 500;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    let mut groups = Vec::new();
+    let mut anyone = 0;
+    let mut everyone = 0;
+
+    for _ in 0..size {
+        let people: Vec<u32> = (0..rng.range(1..=5))
+            .map(|_| loop {
+                let answers = (rng.next_u64() & rng.next_u64()) as u32 & 0x3ff_ffff;
+                if answers != 0 {
+                    break answers;
+                }
+            })
+            .collect();
+
+        anyone += people.iter().fold(0, |acc, p| acc | p).count_ones();
+        everyone += people.iter().fold(!0, |acc, p| acc & p).count_ones();
+
+        groups.push(
+            people
+                .iter()
+                .map(|p| {
+                    (0..26)
+                        .filter(|bit| p >> bit & 1 == 1)
+                        .map(|bit| (b'a' + bit as u8) as char)
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+
+    Ok(Generated::with_answers(
+        groups.join("\n\n") + "\n",
+        anyone,
+        everyone,
+    ))
+}
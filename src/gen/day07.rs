@@ -0,0 +1,102 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 594;
+
+const LAYERS: usize = 7;
+
+const ADJECTIVES: [&str; 33] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "ashen", "dusty",
+    "glossy", "matte", "neon", "rusty", "soft", "stark", "bold", "deep", "frosty", "hazy", "misty",
+    "royal", "sunny",
+];
+const COLORS: [&str; 33] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 2..=1000)?;
+
+    let mut names: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLORS
+                .iter()
+                .map(move |color| format!("{} {}", adjective, color))
+        })
+        .filter(|name| name != "shiny gold")
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    rng.shuffle(&mut names);
+    names.truncate(size - 1);
+
+    let gold = rng.below(size);
+    names.insert(gold, "shiny gold".to_string());
+
+    let mut rules: Vec<String> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let next_layer = ((i * LAYERS / size + 1) * size).div_ceil(LAYERS);
+            let mut children: Vec<usize> = if next_layer < size {
+                (0..rng.range(0..=4))
+                    .map(|_| next_layer + rng.below(size - next_layer))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            children.sort_unstable();
+            children.dedup();
+
+            if children.is_empty() {
+                format!("{} bags contain no other bags.\n", name)
+            } else {
+                let contents: Vec<String> = children
+                    .iter()
+                    .map(|&child| {
+                        let count = rng.range(1..=5);
+                        let bag = if count == 1 { "bag" } else { "bags" };
+                        format!("{} {} {}", count, names[child], bag)
+                    })
+                    .collect();
+                format!("{} bags contain {}.\n", name, contents.join(", "))
+            }
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    Ok(Generated::new(rules.concat()))
+}
@@ -0,0 +1,61 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 400;
+/// Each step writes at most five instructions, so this keeps every jump within the `i16`
+/// arguments the parser accepts.
+pub const MAX_SIZE: usize = i16::MAX as usize / 5;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 2..=MAX_SIZE)?;
+
+    // Every instruction on the terminating path is either `acc`, a `nop` that
+    // would jump back onto the path, or a forward `jmp` over a block that
+    // jumps back onto the path. Flipping any of them loops, except for the
+    // corrupted `jmp` that replaced one of the `nop`s.
+    let corrupted = rng.range(1..=size as i64 - 1) as usize;
+
+    let mut program: Vec<(&str, i64)> = Vec::new();
+    let mut path: Vec<usize> = Vec::new();
+    let mut acc_before_loop = 0;
+    let mut acc = 0;
+
+    for step in 0..size {
+        let pc = program.len();
+        path.push(pc);
+
+        if step == corrupted {
+            acc_before_loop = acc;
+            let target = *rng.choose(&path);
+            program.push(("jmp", target as i64 - pc as i64));
+            continue;
+        }
+
+        match rng.below(5) {
+            0 | 1 => {
+                let arg = rng.range(-50..=50);
+                acc += arg;
+                program.push(("acc", arg));
+            }
+            2 => {
+                let target = *rng.choose(&path);
+                program.push(("nop", target as i64 - pc as i64));
+            }
+            _ => {
+                let skipped = rng.range(1..=4) as usize;
+                program.push(("jmp", skipped as i64 + 1));
+                for _ in 1..skipped {
+                    program.push(("acc", rng.range(-50..=50)));
+                }
+                let target = *rng.choose(&path);
+                program.push(("jmp", target as i64 - program.len() as i64));
+            }
+        }
+    }
+
+    let input = program
+        .iter()
+        .map(|(op, arg)| format!("{} {:+}\n", op, arg))
+        .collect();
+
+    Ok(Generated::with_answers(input, acc_before_loop, acc))
+}
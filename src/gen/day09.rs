@@ -0,0 +1,76 @@
+use crate::days::day09::PREV_NUM_LOOKUP_LEN;
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, PREV_NUM_LOOKUP_LEN * 2..=5000)?;
+
+    'retry: loop {
+        let mut numbers: Vec<u64> = Vec::with_capacity(size);
+        while numbers.len() < PREV_NUM_LOOKUP_LEN {
+            let n = rng.range(1..=50) as u64;
+            if !numbers.contains(&n) {
+                numbers.push(n);
+            }
+        }
+
+        let invalid_at = rng.range((size / 2) as i64..=size as i64 - 1) as usize;
+        while numbers.len() < invalid_at {
+            numbers.push(valid_next(rng, &numbers));
+        }
+
+        let len = rng.range(2..=8) as usize;
+        let start = rng.below(invalid_at - len + 1);
+        let invalid: u64 = numbers[start..start + len].iter().sum();
+
+        let window = &numbers[invalid_at - PREV_NUM_LOOKUP_LEN..];
+        for (i, x) in window.iter().enumerate() {
+            if window[i + 1..].iter().any(|y| x + y == invalid) {
+                continue 'retry;
+            }
+        }
+
+        let prefix: Vec<u64> = std::iter::once(0)
+            .chain(numbers.iter().scan(0, |sum, n| {
+                *sum += n;
+                Some(*sum)
+            }))
+            .collect();
+        for end in 1..=start + len {
+            for begin in 0..end {
+                if (begin, end) != (start, start + len) && prefix[end] - prefix[begin] == invalid {
+                    continue 'retry;
+                }
+            }
+        }
+
+        let range = &numbers[start..start + len];
+        let weakness = range.iter().min().unwrap_or(&0) + range.iter().max().unwrap_or(&0);
+
+        numbers.push(invalid);
+        while numbers.len() < size {
+            numbers.push(valid_next(rng, &numbers));
+        }
+
+        let input = numbers.iter().map(|n| format!("{}\n", n)).collect();
+
+        return Ok(Generated::with_answers(input, invalid, weakness));
+    }
+}
+
+fn valid_next(rng: &mut Rng, numbers: &[u64]) -> u64 {
+    let mut window: Vec<u64> = numbers[numbers.len() - PREV_NUM_LOOKUP_LEN..].to_vec();
+    window.sort_unstable();
+
+    let i = rng.below(8);
+    let j = loop {
+        let j = rng.below(8);
+        if j != i {
+            break j;
+        }
+    };
+
+    window[i] + window[j]
+}
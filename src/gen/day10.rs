@@ -0,0 +1,35 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 100;
+pub const MAX_SIZE: usize = 10_000;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=MAX_SIZE)?;
+
+    let mut adapters = Vec::with_capacity(size);
+    let mut jolts = 0;
+    let mut ones = 0;
+    let mut threes = 1;
+
+    for _ in 0..size {
+        let diff = match rng.below(10) {
+            0..=5 => 1,
+            6 => 2,
+            _ => 3,
+        };
+        match diff {
+            1 => ones += 1,
+            3 => threes += 1,
+            _ => {}
+        }
+
+        jolts += diff;
+        adapters.push(jolts);
+    }
+    rng.shuffle(&mut adapters);
+
+    let mut generated = Generated::new(adapters.iter().map(|a| format!("{}\n", a)).collect());
+    generated.part1 = Some((ones * threes).to_string());
+
+    Ok(generated)
+}
@@ -0,0 +1,27 @@
+use crate::days::day11;
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 95;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=1000)?;
+
+    // Random layouts can oscillate forever, which real inputs never do.
+    loop {
+        let input: String = (0..size)
+            .map(|_| {
+                let mut row: String = (0..size)
+                    .map(|_| if rng.chance(0.8) { 'L' } else { '.' })
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect();
+
+        let grid = day11::parse(&input).map_err(|e| e.to_string())?;
+        if day11::part1(&grid).is_ok() && day11::part2(&grid).is_ok() {
+            return Ok(Generated::new(input));
+        }
+    }
+}
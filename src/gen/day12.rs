@@ -0,0 +1,22 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 780;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    let input = (0..size)
+        .map(|_| match rng.below(7) {
+            0 => format!("L{}\n", rng.choose(&[90, 180, 270])),
+            1 => format!("R{}\n", rng.choose(&[90, 180, 270])),
+            2 | 3 => format!("F{}\n", rng.range(1..=100)),
+            _ => format!(
+                "{}{}\n",
+                rng.choose(&['N', 'S', 'E', 'W']),
+                rng.range(1..=5)
+            ),
+        })
+        .collect();
+
+    Ok(Generated::new(input))
+}
@@ -0,0 +1,57 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 9;
+
+const PRIMES: [u32; 22] = [
+    7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=PRIMES.len())?;
+
+    let mut ids = PRIMES.to_vec();
+    rng.shuffle(&mut ids);
+    ids.truncate(size);
+
+    // Every bus leaves at `timestamp + offset`, so `timestamp` is the earliest
+    // match as long as it is below the product of the (coprime) ids.
+    let modulus: u128 = ids.iter().map(|&id| id as u128).product();
+    let timestamp = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) % modulus;
+
+    let mut schedule: Vec<Option<u32>> = Vec::new();
+    for &id in &ids {
+        let first = (id as u128 - timestamp % id as u128) as usize % id as usize;
+        let mut offset = first + rng.below(3) * id as usize;
+        while schedule.get(offset).copied().flatten().is_some() {
+            offset += id as usize;
+        }
+
+        if schedule.len() <= offset {
+            schedule.resize(offset + 1, None);
+        }
+        schedule[offset] = Some(id);
+    }
+
+    let earliest = rng.range(1_000_000..=1_010_000) as u32;
+    let (wait, id) = ids
+        .iter()
+        .map(|&id| ((id - earliest % id) % id, id))
+        .min()
+        .unwrap_or((0, 0));
+    let ties = ids
+        .iter()
+        .filter(|&&other| (other - earliest % other) % other == wait)
+        .count();
+
+    let schedule: Vec<String> = schedule
+        .iter()
+        .map(|id| id.map_or("x".to_string(), |id| id.to_string()))
+        .collect();
+    let mut generated = Generated::new(format!("{}\n{}\n", earliest, schedule.join(",")));
+    if ties == 1 {
+        generated.part1 = Some((id * wait).to_string());
+    }
+    generated.part2 = Some(timestamp.to_string());
+
+    Ok(generated)
+}
@@ -0,0 +1,30 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 100;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=10_000)?;
+
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut mask: Vec<char> = (0..36)
+            .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+            .collect();
+        for _ in 0..rng.range(1..=9) {
+            let i = rng.below(36);
+            mask[i] = 'X';
+        }
+        input.push_str(&format!("mask = {}\n", mask.iter().collect::<String>()));
+
+        for _ in 0..rng.range(1..=4) {
+            input.push_str(&format!(
+                "mem[{}] = {}\n",
+                rng.range(0..=65535),
+                rng.range(0..=(1 << 36) - 1)
+            ));
+        }
+    }
+
+    Ok(Generated::new(input))
+}
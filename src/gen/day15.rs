@@ -0,0 +1,22 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 7;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=20)?;
+
+    let mut numbers: Vec<i64> = (0..=20).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(size);
+
+    // Repeat an earlier starting number now and then, so the game starts with a gap to recall.
+    for i in 1..numbers.len() {
+        if rng.chance(0.2) {
+            numbers[i] = numbers[rng.below(i)];
+        }
+    }
+
+    let numbers: Vec<String> = numbers.iter().map(i64::to_string).collect();
+
+    Ok(Generated::new(numbers.join(",") + "\n"))
+}
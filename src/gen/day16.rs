@@ -0,0 +1,112 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 240;
+
+const FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+const BASE: u32 = 30;
+const BAND: u32 = 40;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=2000)?;
+
+    // Field `rank` accepts the values of every column with a rank at least as
+    // high, so eliminating the most constrained column first identifies all
+    // of them in turn.
+    let mut names = FIELDS.to_vec();
+    rng.shuffle(&mut names);
+    let mut columns: Vec<usize> = (0..FIELDS.len()).collect();
+    rng.shuffle(&mut columns);
+
+    let band = |rank: usize| BASE + rank as u32 * BAND..=BASE + (rank as u32 + 1) * BAND - 1;
+    let top = BASE + FIELDS.len() as u32 * BAND;
+
+    let mut rules: Vec<String> = names
+        .iter()
+        .enumerate()
+        .map(|(rank, name)| {
+            let first = band(rank);
+            let (low, high) = if rank + 1 < FIELDS.len() {
+                (*band(rank + 1).start(), top - 1)
+            } else {
+                (top + 10, top + 20)
+            };
+            format!(
+                "{}: {}-{} or {}-{}\n",
+                name,
+                first.start(),
+                first.end(),
+                low,
+                high
+            )
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    let ticket = |rng: &mut Rng| {
+        let mut values = vec![0; FIELDS.len()];
+        for (rank, &column) in columns.iter().enumerate() {
+            values[column] =
+                rng.range(*band(rank).start() as i64..=*band(rank).end() as i64) as u32;
+        }
+        values
+    };
+    let join = |values: &[u32]| {
+        values
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let mine = ticket(rng);
+    let departures: u128 = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| name.starts_with("departure"))
+        .map(|(rank, _)| mine[columns[rank]] as u128)
+        .product();
+
+    let mut error_rate = 0;
+    let mut nearby = String::new();
+    for _ in 0..size {
+        let mut values = ticket(rng);
+        if rng.chance(0.25) {
+            let invalid = rng.range(1..=BASE as i64 - 1) as u32;
+            values[rng.below(FIELDS.len())] = invalid;
+            error_rate += invalid;
+        }
+        nearby.push_str(&join(&values));
+        nearby.push('\n');
+    }
+
+    let input = format!(
+        "{}\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        rules.concat(),
+        join(&mine),
+        nearby
+    );
+
+    Ok(Generated::with_answers(input, error_rate, departures))
+}
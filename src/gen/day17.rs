@@ -0,0 +1,19 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 8;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100)?;
+
+    let input = (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect();
+
+    Ok(Generated::new(input))
+}
@@ -0,0 +1,110 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 370;
+
+const MAX_VALUE: u128 = 1_000_000_000_000;
+
+enum Expr {
+    Num(u128),
+    Group(Vec<Expr>, Vec<char>),
+}
+
+impl Expr {
+    fn random(rng: &mut Rng, depth: usize) -> Self {
+        let operands = (0..rng.range(2..=5))
+            .map(|_| {
+                if depth < 2 && rng.chance(0.25) {
+                    Expr::random(rng, depth + 1)
+                } else {
+                    Expr::Num(rng.range(1..=9) as u128)
+                }
+            })
+            .collect::<Vec<_>>();
+        let operators = (1..operands.len())
+            .map(|_| if rng.chance(0.5) { '+' } else { '*' })
+            .collect();
+
+        Expr::Group(operands, operators)
+    }
+
+    fn eval(&self, advanced: bool) -> Option<u128> {
+        match self {
+            Expr::Num(n) => Some(*n),
+            Expr::Group(operands, operators) => {
+                let values = operands
+                    .iter()
+                    .map(|operand| operand.eval(advanced))
+                    .collect::<Option<Vec<_>>>()?;
+
+                let value = if advanced {
+                    let mut product = 1u128;
+                    let mut sum = values[0];
+                    for (op, &value) in operators.iter().zip(&values[1..]) {
+                        if *op == '+' {
+                            sum += value;
+                        } else {
+                            product = product.checked_mul(sum)?;
+                            sum = value;
+                        }
+                    }
+                    product.checked_mul(sum)?
+                } else {
+                    operators.iter().zip(&values[1..]).try_fold(
+                        values[0],
+                        |acc, (op, &value)| {
+                            if *op == '+' {
+                                acc.checked_add(value)
+                            } else {
+                                acc.checked_mul(value)
+                            }
+                        },
+                    )?
+                };
+
+                Some(value).filter(|&value| value <= MAX_VALUE)
+            }
+        }
+    }
+
+    fn write(&self, out: &mut String, nested: bool) {
+        match self {
+            Expr::Num(n) => out.push_str(&n.to_string()),
+            Expr::Group(operands, operators) => {
+                if nested {
+                    out.push('(');
+                }
+                for (i, operand) in operands.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(&format!(" {} ", operators[i - 1]));
+                    }
+                    operand.write(out, true);
+                }
+                if nested {
+                    out.push(')');
+                }
+            }
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    let mut input = String::new();
+    let mut simple = 0;
+    let mut advanced = 0;
+    let mut lines = 0;
+
+    while lines < size {
+        let expr = Expr::random(rng, 0);
+        if let (Some(a), Some(b)) = (expr.eval(false), expr.eval(true)) {
+            lines += 1;
+            simple += a;
+            advanced += b;
+            expr.write(&mut input, false);
+            input.push('\n');
+        }
+    }
+
+    Ok(Generated::with_answers(input, simple, advanced))
+}
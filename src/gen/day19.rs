@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 400;
+
+const DEPTH: usize = 3;
+const RULES_PER_LEVEL: usize = 3;
+const CHUNK_LEN: usize = 1 + (1 << DEPTH);
+
+enum Rule {
+    Char(char),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    // Rule 42 chunks always start with `a` and rule 31 chunks with `b`, so a
+    // message made of whole chunks matches exactly when it is `42{m} 31{n}`
+    // with `m > n`, and anything of another length never matches.
+    let mut ids: Vec<usize> = (1..=150)
+        .filter(|id| ![8, 11, 31, 42].contains(id))
+        .collect();
+    rng.shuffle(&mut ids);
+    let mut ids = ids.into_iter();
+    let mut next_id = || ids.next().unwrap_or_default();
+
+    let mut rules: BTreeMap<usize, Rule> = BTreeMap::new();
+    let a = next_id();
+    let b = next_id();
+    rules.insert(a, Rule::Char('a'));
+    rules.insert(b, Rule::Char('b'));
+
+    let mut level = vec![a, b];
+    for _ in 0..DEPTH {
+        let mut next_level = Vec::new();
+        for _ in 0..RULES_PER_LEVEL {
+            let id = next_id();
+            let alternatives = (0..rng.range(1..=2))
+                .map(|_| vec![*rng.choose(&level), *rng.choose(&level)])
+                .collect();
+            rules.insert(id, Rule::Alternatives(alternatives));
+            next_level.push(id);
+        }
+        level = next_level;
+    }
+
+    rules.insert(42, Rule::Alternatives(vec![vec![a, *rng.choose(&level)]]));
+    rules.insert(31, Rule::Alternatives(vec![vec![b, *rng.choose(&level)]]));
+    rules.insert(8, Rule::Alternatives(vec![vec![42]]));
+    rules.insert(11, Rule::Alternatives(vec![vec![42, 31]]));
+    rules.insert(0, Rule::Alternatives(vec![vec![8, 11]]));
+
+    let mut matching = 0;
+    let mut matching_looped = 0;
+    let mut messages = String::new();
+
+    for _ in 0..size {
+        let message = match rng.below(8) {
+            0..=3 => {
+                let (m, n) = if rng.chance(0.4) {
+                    (2, 1)
+                } else {
+                    let m = rng.range(2..=5);
+                    (m, rng.range(1..=(m - 1).min(3)))
+                };
+                if (m, n) == (2, 1) {
+                    matching += 1;
+                }
+                matching_looped += 1;
+                chunks(rng, &rules, m, n)
+            }
+            4 | 5 => {
+                let m = rng.range(1..=3);
+                let n = rng.range(m..=m + 2);
+                chunks(rng, &rules, m, n)
+            }
+            6 => {
+                let (n, m) = (rng.range(1..=2), rng.range(2..=3));
+                let mut message = chunks(rng, &rules, 0, n);
+                message.push_str(&chunks(rng, &rules, m, 0));
+                message
+            }
+            _ => loop {
+                let len = rng.range(CHUNK_LEN as i64..=6 * CHUNK_LEN as i64) as usize;
+                if !len.is_multiple_of(CHUNK_LEN) {
+                    break (0..len).map(|_| *rng.choose(&['a', 'b'])).collect();
+                }
+            },
+        };
+        messages.push_str(&message);
+        messages.push('\n');
+    }
+
+    let mut lines: Vec<String> = rules
+        .iter()
+        .map(|(id, rule)| match rule {
+            Rule::Char(c) => format!("{}: \"{}\"\n", id, c),
+            Rule::Alternatives(alternatives) => {
+                let alternatives: Vec<String> = alternatives
+                    .iter()
+                    .map(|seq| {
+                        seq.iter()
+                            .map(usize::to_string)
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .collect();
+                format!("{}: {}\n", id, alternatives.join(" | "))
+            }
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+
+    Ok(Generated::with_answers(
+        format!("{}\n{}", lines.concat(), messages),
+        matching,
+        matching_looped,
+    ))
+}
+
+fn chunks(rng: &mut Rng, rules: &BTreeMap<usize, Rule>, m: i64, n: i64) -> String {
+    let mut message = String::new();
+    for _ in 0..m {
+        expand(rng, rules, 42, &mut message);
+    }
+    for _ in 0..n {
+        expand(rng, rules, 31, &mut message);
+    }
+    message
+}
+
+fn expand(rng: &mut Rng, rules: &BTreeMap<usize, Rule>, id: usize, out: &mut String) {
+    match &rules[&id] {
+        Rule::Char(c) => out.push(*c),
+        Rule::Alternatives(alternatives) => {
+            for &id in rng.choose(alternatives) {
+                expand(rng, rules, id, out);
+            }
+        }
+    }
+}
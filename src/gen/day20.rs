@@ -0,0 +1,195 @@
+use std::collections::{BTreeSet, HashSet};
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 12;
+
+const SEAMONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+type Grid = Vec<Vec<bool>>;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 3..=12)?;
+
+    let (image, monsters) = loop {
+        let (image, monsters) = image_with_monsters(rng, size * 8, size * size / 4);
+        if count_monsters(&image) == monsters
+            && orientations(&image)
+                .iter()
+                .skip(1)
+                .all(|image| count_monsters(image) == 0)
+        {
+            break (image, monsters);
+        }
+    };
+    let roughness = image.iter().flatten().filter(|&&p| p).count() - monsters * 15;
+
+    // Neighbouring tiles share their border rows, so lay the tiles out on one
+    // grid of `9 * size + 1` cells and give every border segment a pattern no
+    // other segment has in either direction.
+    let width = size * 9 + 1;
+    let mut grid: Grid = (0..width)
+        .map(|_| (0..width).map(|_| rng.chance(0.5)).collect())
+        .collect();
+    for (y, row) in image.iter().enumerate() {
+        for (x, &pixel) in row.iter().enumerate() {
+            grid[y / 8 * 9 + 1 + y % 8][x / 8 * 9 + 1 + x % 8] = pixel;
+        }
+    }
+
+    let segments: Vec<Vec<(usize, usize)>> = (0..=size)
+        .flat_map(|line| {
+            (0..size).flat_map(move |tile| {
+                let horizontal = (0..10).map(move |i| (line * 9, tile * 9 + i)).collect();
+                let vertical = (0..10).map(move |i| (tile * 9 + i, line * 9)).collect();
+                vec![horizontal, vertical]
+            })
+        })
+        .collect();
+
+    let mut used = HashSet::new();
+    for cells in &segments {
+        loop {
+            let bits = cells
+                .iter()
+                .fold(0u16, |acc, &(y, x)| acc << 1 | grid[y][x] as u16);
+            let reversed = bits.reverse_bits() >> 6;
+            if bits != reversed && used.insert(bits.min(reversed)) {
+                break;
+            }
+
+            for &(y, x) in &cells[1..9] {
+                grid[y][x] = rng.chance(0.5);
+            }
+        }
+    }
+
+    let mut ids = BTreeSet::new();
+    while ids.len() < size * size {
+        ids.insert(rng.range(1000..=9999) as u16);
+    }
+    let mut ids: Vec<u16> = ids.into_iter().collect();
+    rng.shuffle(&mut ids);
+
+    let corners: u128 = [0, size - 1, size * (size - 1), size * size - 1]
+        .iter()
+        .map(|&i| ids[i] as u128)
+        .product();
+
+    let mut tiles: Vec<String> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let (row, col) = (i / size, i % size);
+            let tile: Grid = grid[row * 9..=row * 9 + 9]
+                .iter()
+                .map(|line| line[col * 9..=col * 9 + 9].to_vec())
+                .collect();
+            let orientation = rng.below(8);
+            let tile = orientations(&tile).swap_remove(orientation);
+
+            let mut out = format!("Tile {}:\n", id);
+            for line in tile {
+                out.extend(line.iter().map(|&p| if p { '#' } else { '.' }));
+                out.push('\n');
+            }
+            out
+        })
+        .collect();
+    rng.shuffle(&mut tiles);
+
+    Ok(Generated::with_answers(
+        tiles.join("\n"),
+        corners,
+        roughness,
+    ))
+}
+
+fn image_with_monsters(rng: &mut Rng, width: usize, target: usize) -> (Grid, usize) {
+    let mut image: Grid = (0..width)
+        .map(|_| (0..width).map(|_| rng.chance(0.3)).collect())
+        .collect();
+    let mut taken = vec![vec![false; width]; width];
+    let mut planted = 0;
+
+    for _ in 0..target * 50 {
+        if planted == target {
+            break;
+        }
+
+        // Keep a one pixel margin so the monster is inside the scanned area
+        // however the assembled image ends up oriented.
+        let x = 1 + rng.below(width - SEAMONSTER[0].len() - 1);
+        let y = 1 + rng.below(width - SEAMONSTER.len() - 1);
+        let area = |dy: usize, dx: usize| (y - 1 + dy, x - 1 + dx);
+
+        if (0..SEAMONSTER.len() + 2).any(|dy| {
+            (0..SEAMONSTER[0].len() + 2).any(|dx| {
+                let (y, x) = area(dy, dx);
+                taken[y][x]
+            })
+        }) {
+            continue;
+        }
+
+        for dy in 0..SEAMONSTER.len() + 2 {
+            for dx in 0..SEAMONSTER[0].len() + 2 {
+                let (y, x) = area(dy, dx);
+                taken[y][x] = true;
+            }
+        }
+        for (dy, row) in SEAMONSTER.iter().enumerate() {
+            for (dx, c) in row.chars().enumerate() {
+                if c == '#' {
+                    image[y + dy][x + dx] = true;
+                }
+            }
+        }
+        planted += 1;
+    }
+
+    (image, planted)
+}
+
+fn count_monsters(image: &Grid) -> usize {
+    let width = image.len();
+
+    (0..=width - SEAMONSTER.len())
+        .flat_map(|y| (0..=width - SEAMONSTER[0].len()).map(move |x| (y, x)))
+        .filter(|&(y, x)| {
+            SEAMONSTER.iter().enumerate().all(|(dy, row)| {
+                row.chars()
+                    .enumerate()
+                    .all(|(dx, c)| c != '#' || image[y + dy][x + dx])
+            })
+        })
+        .count()
+}
+
+fn orientations(grid: &Grid) -> Vec<Grid> {
+    let rotate = |grid: &Grid| -> Grid {
+        let n = grid.len();
+        (0..n)
+            .map(|y| (0..n).map(|x| grid[n - 1 - x][y]).collect())
+            .collect()
+    };
+
+    let mut all = vec![grid.clone()];
+    for i in 1..8 {
+        let next = if i == 4 {
+            all[3]
+                .iter()
+                .map(|row| row.iter().rev().copied().collect())
+                .collect()
+        } else {
+            rotate(&all[i - 1])
+        };
+        all.push(next);
+    }
+
+    all
+}
@@ -0,0 +1,103 @@
+use std::collections::BTreeSet;
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 40;
+
+const ALLERGENS: [&str; 8] = [
+    "dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=10_000)?;
+
+    let mut names = BTreeSet::new();
+    while names.len() < 200 {
+        names.insert(rng.word(4..=8));
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    rng.shuffle(&mut names);
+    let (dangerous, safe) = names.split_at(ALLERGENS.len());
+
+    let mut foods: Vec<(BTreeSet<usize>, BTreeSet<usize>)> = (0..size)
+        .map(|_| {
+            let mut allergens = BTreeSet::new();
+            for _ in 0..rng.range(1..=3) {
+                allergens.insert(rng.below(ALLERGENS.len()));
+            }
+            let mut ingredients: BTreeSet<usize> = allergens.clone();
+            for i in 0..ALLERGENS.len() {
+                if rng.chance(0.3) {
+                    ingredients.insert(i);
+                }
+            }
+            for _ in 0..rng.range(10..=30) {
+                ingredients.insert(ALLERGENS.len() + rng.below(safe.len()));
+            }
+            (ingredients, allergens)
+        })
+        .collect();
+
+    // Every allergen's candidates must narrow down to its own ingredient, so
+    // add a food that rules out whatever else is still shared.
+    for allergen in 0..ALLERGENS.len() {
+        let shared = foods
+            .iter()
+            .filter(|(_, allergens)| allergens.contains(&allergen))
+            .map(|(ingredients, _)| ingredients.clone())
+            .reduce(|a, b| a.intersection(&b).copied().collect());
+
+        let expected: BTreeSet<usize> = std::iter::once(allergen).collect();
+        if shared.as_ref() != Some(&expected) {
+            let shared = shared.unwrap_or_default();
+            let mut ingredients = expected.clone();
+            for _ in 0..rng.range(10..=30) {
+                let ingredient = ALLERGENS.len() + rng.below(safe.len());
+                if !shared.contains(&ingredient) {
+                    ingredients.insert(ingredient);
+                }
+            }
+            let at = rng.below(foods.len() + 1);
+            foods.insert(at, (ingredients, expected));
+        }
+    }
+
+    let safe_count: usize = foods
+        .iter()
+        .map(|(ingredients, _)| {
+            ingredients
+                .iter()
+                .filter(|&&i| i >= ALLERGENS.len())
+                .count()
+        })
+        .sum();
+
+    let name = |i: usize| {
+        if i < ALLERGENS.len() {
+            &dangerous[i]
+        } else {
+            &safe[i - ALLERGENS.len()]
+        }
+    };
+
+    let input = foods
+        .iter()
+        .map(|(ingredients, allergens)| {
+            let mut ingredients: Vec<&str> =
+                ingredients.iter().map(|&i| name(i).as_str()).collect();
+            rng.shuffle(&mut ingredients);
+            let allergens: Vec<&str> = allergens.iter().map(|&a| ALLERGENS[a]).collect();
+            format!(
+                "{} (contains {})\n",
+                ingredients.join(" "),
+                allergens.join(", ")
+            )
+        })
+        .collect();
+
+    Ok(Generated::with_answers(
+        input,
+        safe_count,
+        dangerous.join(","),
+    ))
+}
@@ -0,0 +1,23 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 25;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=1000)?;
+
+    let mut cards: Vec<usize> = (1..=size * 2).collect();
+    rng.shuffle(&mut cards);
+
+    let deck = |cards: &[usize]| {
+        cards
+            .iter()
+            .map(|card| format!("{}\n", card))
+            .collect::<String>()
+    };
+
+    Ok(Generated::new(format!(
+        "Player 1:\n{}\nPlayer 2:\n{}",
+        deck(&cards[..size]),
+        deck(&cards[size..])
+    )))
+}
@@ -0,0 +1,14 @@
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 9;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 9..=9)?;
+
+    let mut cups: Vec<usize> = (1..=size).collect();
+    rng.shuffle(&mut cups);
+
+    Ok(Generated::new(
+        cups.iter().map(usize::to_string).collect::<String>() + "\n",
+    ))
+}
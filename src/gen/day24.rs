@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 400;
+
+const DIRECTIONS: [(&str, (i64, i64)); 6] = [
+    ("e", (2, 0)),
+    ("se", (1, -1)),
+    ("sw", (-1, -1)),
+    ("w", (-2, 0)),
+    ("nw", (-1, 1)),
+    ("ne", (1, 1)),
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=100_000)?;
+
+    let targets: Vec<(i64, i64)> = (0..(size * 3 / 4).max(1))
+        .map(|_| {
+            let y = rng.range(-10..=10);
+            (rng.range(-10..=10) * 2 + y.rem_euclid(2), y)
+        })
+        .collect();
+
+    let mut flips: HashMap<(i64, i64), usize> = HashMap::new();
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let target = *rng.choose(&targets);
+        *flips.entry(target).or_default() += 1;
+
+        let mut path: Vec<usize> = (0..rng.range(5..=15)).map(|_| rng.below(6)).collect();
+        let (mut x, mut y) = path.iter().fold((0, 0), |(x, y), &d| {
+            let (dx, dy) = DIRECTIONS[d].1;
+            (x + dx, y + dy)
+        });
+
+        while y != target.1 {
+            let d = match (y < target.1, x < target.0) {
+                (true, true) => 5,
+                (true, false) => 4,
+                (false, true) => 1,
+                (false, false) => 2,
+            };
+            path.push(d);
+            x += DIRECTIONS[d].1 .0;
+            y += DIRECTIONS[d].1 .1;
+        }
+        while x != target.0 {
+            let d = if x < target.0 { 0 } else { 3 };
+            path.push(d);
+            x += DIRECTIONS[d].1 .0;
+        }
+
+        rng.shuffle(&mut path);
+        input.extend(path.iter().map(|&d| DIRECTIONS[d].0));
+        input.push('\n');
+    }
+
+    let mut generated = Generated::new(input);
+    generated.part1 = Some(flips.values().filter(|&&n| n % 2 == 1).count().to_string());
+
+    Ok(generated)
+}
@@ -0,0 +1,23 @@
+use crate::{days::day25::part2, modular::modpow};
+
+use super::{check_size, Generated, Rng};
+
+pub const DEFAULT_SIZE: usize = 10_000_000;
+
+const MODULUS: u64 = 20_201_227;
+const SUBJECT: u64 = 7;
+
+pub fn generate(rng: &mut Rng, size: usize) -> Result<Generated, String> {
+    check_size(size, 1..=MODULUS as usize - 1)?;
+
+    let card_loop = rng.range(1..=size as i64) as u64;
+    let door_loop = rng.range(1..=size as i64) as u64;
+    let door = modpow(SUBJECT, door_loop, MODULUS);
+    let card = modpow(SUBJECT, card_loop, MODULUS);
+
+    Ok(Generated::with_answers(
+        format!("{}\n{}\n", door, card),
+        modpow(door, card_loop, MODULUS),
+        part2(&(0, 0))?,
+    ))
+}
@@ -0,0 +1,140 @@
+use std::ops::RangeInclusive;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Generated {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn with_answers<A: ToString, B: ToString>(input: String, part1: A, part2: B) -> Self {
+        Generated {
+            input,
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// SplitMix64, so a seed produces the same input on every platform and release.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = (*range.end() - *range.start()) as u128 + 1;
+        *range.start() + ((self.next_u64() as u128 * span) >> 64) as i64
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    pub fn word(&mut self, len: RangeInclusive<i64>) -> String {
+        (0..self.range(len))
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+macro_rules! dispatch_gen {
+    ($day:expr, $rng:expr, $size:expr; $($n:literal => $module:ident),*) => {
+        match $day {
+            $($n => $module::generate($rng, $size.unwrap_or($module::DEFAULT_SIZE)),)*
+            x => Err(format!("invalid day: {}", x)),
+        }
+    };
+}
+
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Result<Generated, String> {
+    let rng = &mut Rng::new(seed);
+
+    dispatch_gen!(day, rng, size;
+        1 => day01, 2 => day02, 3 => day03, 4 => day04, 5 => day05,
+        6 => day06, 7 => day07, 8 => day08, 9 => day09, 10 => day10,
+        11 => day11, 12 => day12, 13 => day13, 14 => day14, 15 => day15,
+        16 => day16, 17 => day17, 18 => day18, 19 => day19, 20 => day20,
+        21 => day21, 22 => day22, 23 => day23, 24 => day24, 25 => day25)
+}
+
+fn check_size(size: usize, range: RangeInclusive<usize>) -> Result<(), String> {
+    if range.contains(&size) {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid size: {} (expected {} to {})",
+            size,
+            range.start(),
+            range.end()
+        ))
+    }
+}
@@ -0,0 +1,385 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::bigint::Number;
+
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = ()> {
+    indices: HashMap<N, usize>,
+    labels: Vec<N>,
+    successors: Vec<Vec<(usize, W)>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+#[derive(Clone)]
+enum Visit {
+    Unvisited,
+    Visiting,
+    Done(Number),
+}
+
+impl<N: Clone + Eq + Hash, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+impl<N: Clone + Eq + Hash, W> Graph<N, W> {
+    pub fn new() -> Self {
+        Graph {
+            indices: HashMap::new(),
+            labels: Vec::new(),
+            successors: Vec::new(),
+            predecessors: Vec::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, label: N) -> bool {
+        if self.indices.contains_key(&label) {
+            return false;
+        }
+
+        self.index_or_insert(label);
+        true
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let from = self.index_or_insert(from);
+        let to = self.index_or_insert(to);
+
+        self.successors[from].push((to, weight));
+        self.predecessors[to].push(from);
+    }
+
+    fn index_or_insert(&mut self, label: N) -> usize {
+        if let Some(&index) = self.indices.get(&label) {
+            return index;
+        }
+
+        let index = self.labels.len();
+        self.indices.insert(label.clone(), index);
+        self.labels.push(label);
+        self.successors.push(Vec::new());
+        self.predecessors.push(Vec::new());
+
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn contains(&self, label: &N) -> bool {
+        self.indices.contains_key(label)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.labels.iter()
+    }
+
+    pub fn successors(&self, label: &N) -> impl Iterator<Item = (&N, &W)> {
+        self.indices.get(label).into_iter().flat_map(move |&i| {
+            self.successors[i]
+                .iter()
+                .map(move |(to, weight)| (&self.labels[*to], weight))
+        })
+    }
+
+    pub fn predecessors(&self, label: &N) -> impl Iterator<Item = &N> {
+        self.indices.get(label).into_iter().flat_map(move |&i| {
+            self.predecessors[i]
+                .iter()
+                .map(move |&from| &self.labels[from])
+        })
+    }
+
+    /// Nodes reachable from `label` by following edges forwards, in breadth-first order.
+    pub fn descendants(&self, label: &N) -> Vec<&N> {
+        self.reachable(label, |i| {
+            self.successors[i].iter().map(|(to, _)| *to).collect()
+        })
+    }
+
+    /// Nodes that can reach `label`, in breadth-first order.
+    pub fn ancestors(&self, label: &N) -> Vec<&N> {
+        self.reachable(label, |i| self.predecessors[i].clone())
+    }
+
+    fn reachable<F>(&self, label: &N, next: F) -> Vec<&N>
+    where
+        F: Fn(usize) -> Vec<usize>,
+    {
+        let mut seen = vec![false; self.len()];
+        let mut queue: VecDeque<usize> = self.indices.get(label).copied().into_iter().collect();
+        let mut found = Vec::new();
+
+        while let Some(i) = queue.pop_front() {
+            for n in next(i) {
+                if !seen[n] {
+                    seen[n] = true;
+                    found.push(&self.labels[n]);
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        found
+    }
+
+    pub fn topological_sort(&self) -> Result<Vec<&N>, String> {
+        let mut in_degree: Vec<usize> = self.predecessors.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<usize> = (0..self.len()).filter(|&i| in_degree[i] == 0).collect();
+        let mut sorted = Vec::with_capacity(self.len());
+
+        while let Some(i) = queue.pop_front() {
+            sorted.push(&self.labels[i]);
+            for &(to, _) in &self.successors[i] {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        if sorted.len() == self.len() {
+            Ok(sorted)
+        } else {
+            Err("graph contains a cycle".to_string())
+        }
+    }
+
+    /// Returns the nodes of some cycle in edge order, if there is one.
+    pub fn find_cycle(&self) -> Option<Vec<&N>> {
+        let mut visits = vec![Visit::Unvisited; self.len()];
+        let mut stack = Vec::new();
+
+        (0..self.len()).find_map(|i| match visits[i] {
+            Visit::Unvisited => self.cycle_from(i, &mut visits, &mut stack),
+            _ => None,
+        })
+    }
+
+    fn cycle_from(
+        &self,
+        i: usize,
+        visits: &mut [Visit],
+        stack: &mut Vec<usize>,
+    ) -> Option<Vec<&N>> {
+        visits[i] = Visit::Visiting;
+        stack.push(i);
+
+        for &(to, _) in &self.successors[i] {
+            match visits[to] {
+                Visit::Visiting => {
+                    let start = stack.iter().position(|&n| n == to).unwrap_or(0);
+                    return Some(stack[start..].iter().map(|&n| &self.labels[n]).collect());
+                }
+                Visit::Unvisited => {
+                    if let Some(cycle) = self.cycle_from(to, visits, stack) {
+                        return Some(cycle);
+                    }
+                }
+                Visit::Done(_) => {}
+            }
+        }
+
+        stack.pop();
+        visits[i] = Visit::Done(Number::default());
+        None
+    }
+
+    /// Counts the distinct paths from `from` to `to`; only the part of the graph reachable
+    /// from `from` must be acyclic.
+    pub fn count_paths(&self, from: &N, to: &N) -> Result<Number, String> {
+        let (from, to) = match (self.indices.get(from), self.indices.get(to)) {
+            (Some(&from), Some(&to)) => (from, to),
+            _ => return Ok(Number::default()),
+        };
+
+        self.fold_paths(
+            from,
+            &mut vec![Visit::Unvisited; self.len()],
+            &|i| Number::from(u8::from(i == to)),
+            &|_, paths| paths,
+        )
+    }
+
+    /// Sums the product of edge weights over every non-empty path starting at `from`, such
+    /// as the number of bags nested inside a bag.
+    pub fn count_weighted_paths(&self, from: &N) -> Result<Number, String>
+    where
+        W: Copy + Into<u128>,
+    {
+        let from = match self.indices.get(from) {
+            Some(&from) => from,
+            None => return Ok(Number::default()),
+        };
+
+        self.fold_paths(
+            from,
+            &mut vec![Visit::Unvisited; self.len()],
+            &|_| Number::default(),
+            &|&weight, paths| Number::from(weight.into()) * (paths + Number::from(1u8)),
+        )
+    }
+
+    fn fold_paths(
+        &self,
+        i: usize,
+        visits: &mut [Visit],
+        base: &dyn Fn(usize) -> Number,
+        edge: &dyn Fn(&W, Number) -> Number,
+    ) -> Result<Number, String> {
+        match &visits[i] {
+            Visit::Done(value) => return Ok(value.clone()),
+            Visit::Visiting => return Err("graph contains a cycle".to_string()),
+            Visit::Unvisited => {}
+        }
+
+        visits[i] = Visit::Visiting;
+        let mut total = base(i);
+        for (to, weight) in &self.successors[i] {
+            let paths = self.fold_paths(*to, visits, base, edge)?;
+            total = total + edge(weight, paths);
+        }
+        visits[i] = Visit::Done(total.clone());
+
+        Ok(total)
+    }
+}
+
+/// Pairs as many left items as possible with distinct candidates from their lists.
+pub fn maximum_matching<L, R, I>(candidates: I) -> Vec<(L, R)>
+where
+    I: IntoIterator<Item = (L, Vec<R>)>,
+    R: Clone + Eq + Hash,
+{
+    let (lefts, rights, adjacency) = index_candidates(candidates);
+    let matched = match_indices(&adjacency, rights.len());
+
+    lefts
+        .into_iter()
+        .zip(matched)
+        .filter_map(|(left, right)| right.map(|r| (left, rights[r].clone())))
+        .collect()
+}
+
+/// Pairs every left item with a distinct candidate, failing unless there is exactly one way
+/// to do so.
+pub fn unique_matching<L, R, I>(candidates: I) -> Result<Vec<(L, R)>, String>
+where
+    I: IntoIterator<Item = (L, Vec<R>)>,
+    R: Clone + Eq + Hash,
+{
+    let (lefts, rights, adjacency) = index_candidates(candidates);
+    let matched = match_indices(&adjacency, rights.len());
+
+    if matched.iter().any(Option::is_none) {
+        return Err("no complete matching".to_string());
+    }
+
+    // Every left item is matched, so a free right item reached by an alternating path can be
+    // reached straight from the path's last left item, which could take it instead.
+    let mut taken = vec![false; rights.len()];
+    for &r in matched.iter().flatten() {
+        taken[r] = true;
+    }
+    if adjacency.iter().flatten().any(|&r| !taken[r]) {
+        return Err("more than one complete matching".to_string());
+    }
+
+    // Otherwise it is unique exactly when there is no cycle alternating between unmatched and
+    // matched edges.
+    let mut alternating: Graph<(bool, usize)> = Graph::new();
+    for (l, candidates) in adjacency.iter().enumerate() {
+        for &r in candidates {
+            if matched[l] == Some(r) {
+                alternating.add_edge((true, r), (false, l), ());
+            } else {
+                alternating.add_edge((false, l), (true, r), ());
+            }
+        }
+    }
+
+    if alternating.find_cycle().is_some() {
+        return Err("more than one complete matching".to_string());
+    }
+
+    Ok(lefts
+        .into_iter()
+        .zip(matched)
+        .filter_map(|(left, right)| right.map(|r| (left, rights[r].clone())))
+        .collect())
+}
+
+type IndexedCandidates<L, R> = (Vec<L>, Vec<R>, Vec<Vec<usize>>);
+
+fn index_candidates<L, R, I>(candidates: I) -> IndexedCandidates<L, R>
+where
+    I: IntoIterator<Item = (L, Vec<R>)>,
+    R: Clone + Eq + Hash,
+{
+    let mut lefts = Vec::new();
+    let mut rights: Vec<R> = Vec::new();
+    let mut indices: HashMap<R, usize> = HashMap::new();
+    let mut adjacency = Vec::new();
+
+    for (left, candidates) in candidates {
+        lefts.push(left);
+        adjacency.push(
+            candidates
+                .into_iter()
+                .map(|right| {
+                    *indices.entry(right.clone()).or_insert_with(|| {
+                        rights.push(right);
+                        rights.len() - 1
+                    })
+                })
+                .collect(),
+        );
+    }
+
+    (lefts, rights, adjacency)
+}
+
+fn match_indices(adjacency: &[Vec<usize>], rights: usize) -> Vec<Option<usize>> {
+    let mut owners: Vec<Option<usize>> = vec![None; rights];
+
+    for l in 0..adjacency.len() {
+        augment(l, adjacency, &mut vec![false; rights], &mut owners);
+    }
+
+    let mut matched = vec![None; adjacency.len()];
+    for (r, owner) in owners.into_iter().enumerate() {
+        if let Some(l) = owner {
+            matched[l] = Some(r);
+        }
+    }
+
+    matched
+}
+
+fn augment(
+    l: usize,
+    adjacency: &[Vec<usize>],
+    seen: &mut [bool],
+    owners: &mut [Option<usize>],
+) -> bool {
+    for &r in &adjacency[l] {
+        if !seen[r] {
+            seen[r] = true;
+            if owners[r].is_none_or(|owner| augment(owner, adjacency, seen, owners)) {
+                owners[r] = Some(l);
+                return true;
+            }
+        }
+    }
+
+    false
+}
@@ -0,0 +1,388 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse_lines, ParseError};
+
+pub trait Cell: Sized {
+    /// Describes the accepted characters in parse errors, e.g. "`.` or `#`".
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for bool {
+    const EXPECTED: &'static str = "`.` or `#`";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    RotateRight,
+    Rotate180,
+    RotateLeft,
+    FlipHorizontally,
+    FlipVertically,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::RotateRight,
+        Transform::Rotate180,
+        Transform::RotateLeft,
+        Transform::FlipHorizontally,
+        Transform::FlipVertically,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Transform::RotateRight
+                | Transform::RotateLeft
+                | Transform::Transpose
+                | Transform::AntiTranspose
+        )
+    }
+
+    /// Maps a position in the transformed grid back to the source grid of `width` by `height`.
+    fn source(self, (x, y): (usize, usize), width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Transform::Identity => (x, y),
+            Transform::RotateRight => (y, height - 1 - x),
+            Transform::Rotate180 => (width - 1 - x, height - 1 - y),
+            Transform::RotateLeft => (width - 1 - y, x),
+            Transform::FlipHorizontally => (width - 1 - x, y),
+            Transform::FlipVertically => (x, height - 1 - y),
+            Transform::Transpose => (y, x),
+            Transform::AntiTranspose => (width - 1 - y, height - 1 - x),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "row {} has {} cells, expected {}",
+                y,
+                rows[y].len(),
+                width
+            ));
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Grid {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| f(x, y))
+                .collect(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        T: Cell,
+    {
+        let mut width = None;
+
+        let rows = parse_lines(input, |line| {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| {
+                    T::from_char(c).ok_or_else(|| {
+                        ParseError::at(line, &line[i..i + c.len_utf8()], T::EXPECTED)
+                    })
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if row.len() != width => {
+                    return Err(ParseError::at(
+                        line,
+                        line,
+                        &format!("a row of {} cells", width),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            Ok(row)
+        })?;
+
+        Ok(Grid::from_rows(rows).expect("rows are checked while parsing"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Wraps both coordinates around the edges, as for a grid repeating in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        &self[(
+            x.rem_euclid(self.width as isize) as usize,
+            y.rem_euclid(self.height as isize) as usize,
+        )]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, val: T) {
+        self[(x, y)] = val;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |y| &self[(x, y)])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn neighbours(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        (y.saturating_sub(1)..=y + 1)
+            .flat_map(move |ny| (x.saturating_sub(1)..=x + 1).map(move |nx| (nx, ny)))
+            .filter(move |&(nx, ny)| (nx, ny) != (x, y) && nx < width && ny < height)
+    }
+
+    pub fn orthogonal_neighbours(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours((x, y))
+            .filter(move |&(nx, ny)| nx == x || ny == y)
+    }
+
+    pub fn transform(&self, transform: Transform) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (width, height) = if transform.swaps_axes() {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+
+        Grid::from_fn(width, height, |x, y| {
+            self[transform.source((x, y), self.width, self.height)].clone()
+        })
+    }
+
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::RotateRight)
+    }
+
+    pub fn flip_horizontally(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transform(Transform::FlipHorizontally)
+    }
+
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> View<'_, T> {
+        assert!(
+            x + width <= self.width && y + height <= self.height,
+            "view out of bounds"
+        );
+
+        View {
+            grid: self,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({}, {}) out of bounds", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "({}, {}) out of bounds", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.view(0, 0, self.width, self.height).fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "row {} out of bounds", y);
+        &self.grid.row(self.y + y)[self.x..self.x + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + '_ {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| ((x, y), cell)))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self.grid[(self.x + x, self.y + y)].clone()
+        })
+    }
+}
+
+impl<T: Cell> fmt::Display for View<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+        }
+
+        Ok(())
+    }
+}
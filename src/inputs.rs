@@ -0,0 +1,167 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    iter,
+    path::{Path, PathBuf},
+};
+
+/// Where `aoc` looks for `<day>.txt` when no input is given.
+pub const CACHE_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn day_file(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("{}.txt", day))
+}
+
+/// Resolves each path to an input for `day`: files are used as given and directories
+/// contribute their `<day>.txt`. With no paths, the cached input is used if present,
+/// otherwise stdin.
+pub fn resolve(day: u8, paths: &[PathBuf], cache_dir: &Path) -> Result<Vec<Source>, String> {
+    if paths.is_empty() {
+        let cached = day_file(cache_dir, day);
+
+        return Ok(vec![if cached.is_file() {
+            Source::File(cached)
+        } else {
+            Source::Stdin
+        }]);
+    }
+
+    paths
+        .iter()
+        .map(|path| {
+            if path.is_dir() {
+                let file = day_file(path, day);
+                if file.is_file() {
+                    Ok(Source::File(file))
+                } else {
+                    Err(format!("{} has no input for day {}", path.display(), day))
+                }
+            } else {
+                Ok(Source::File(path.clone()))
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    Empty,
+    Binary,
+    ErrorPage,
+    MissingNewline,
+}
+
+impl Problem {
+    /// Whether solving should be refused rather than merely warned about.
+    pub fn is_fatal(self) -> bool {
+        self != Problem::MissingNewline
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Empty => write!(f, "input is empty"),
+            Problem::Binary => write!(f, "input contains NUL bytes"),
+            Problem::ErrorPage => write!(f, "input is an error page, not a puzzle input"),
+            Problem::MissingNewline => {
+                write!(f, "input does not end with a newline and may be truncated")
+            }
+        }
+    }
+}
+
+/// Looks for signs that an input was never downloaded properly or was cut short.
+pub fn check(input: &str) -> Option<Problem> {
+    let trimmed = strip_bom(input).trim_start();
+
+    if trimmed.is_empty() {
+        Some(Problem::Empty)
+    } else if input.contains('\0') {
+        Some(Problem::Binary)
+    } else if trimmed.starts_with("<!DOCTYPE")
+        || trimmed.starts_with("<html")
+        || trimmed.starts_with("Puzzle inputs differ by user")
+    {
+        Some(Problem::ErrorPage)
+    } else if !input.ends_with('\n') {
+        Some(Problem::MissingNewline)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub source: Source,
+    pub text: String,
+    /// A non-fatal problem found by `check`.
+    pub warning: Option<Problem>,
+}
+
+/// Reads `source`, failing on fatal problems.
+pub fn load(source: Source) -> Result<Input, String> {
+    let text = match &source {
+        Source::Stdin => {
+            let mut text = String::new();
+            io::stdin().lock().read_to_string(&mut text).map(|_| text)
+        }
+        Source::File(path) => fs::read_to_string(path),
+    }
+    .map_err(|e| format!("failed to read {}: {}", source, e))?;
+
+    match check(&text) {
+        Some(problem) if problem.is_fatal() => Err(problem.to_string()),
+        warning => Ok(Input {
+            source,
+            text,
+            warning,
+        }),
+    }
+}
+
+pub const BOM: char = '\u{feff}';
+
+pub fn strip_bom(input: &str) -> &str {
+    input.strip_prefix(BOM).unwrap_or(input)
+}
+
+/// The lines of `input` without a BOM, `\n` or `\r\n` endings, or trailing whitespace. Each
+/// line is a slice of `input`, so `ParseError::at` can still locate it.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    strip_bom(input).lines().map(str::trim_end)
+}
+
+/// Groups the lines of `input` into blocks separated by one or more blank lines. Each block
+/// is a slice of `input` from the start of its first line to the end of its last.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = lines(input).peekable();
+    let offset = move |line: &str| line.as_ptr() as usize - input.as_ptr() as usize;
+
+    iter::from_fn(move || {
+        while lines.next_if(|line| line.is_empty()).is_some() {}
+
+        let first = lines.next()?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+            last = line;
+        }
+
+        Some(&input[offset(first)..offset(last) + last.len()])
+    })
+}
@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+pub fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+pub fn array<I>(values: I) -> String
+where
+    I: IntoIterator<Item = String>,
+{
+    format!("[{}]", values.into_iter().collect::<Vec<_>>().join(","))
+}
+
+/// Values must already be encoded, e.g. with `string` or `array`.
+pub fn object(fields: &[(&str, String)]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{}", string(key), value))
+        .collect();
+
+    format!("{{{}}}", fields.join(","))
+}
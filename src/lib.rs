@@ -1 +1,593 @@
+use std::{
+    env, error, fmt,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
+pub mod automaton;
+pub mod bench;
+pub mod bigint;
+pub mod checkpoint;
+pub mod client;
+pub mod combinatorics;
+pub mod crosscheck;
+pub mod days;
+pub mod gen;
+pub mod graph;
+pub mod grid;
+pub mod inputs;
+pub mod json;
+pub mod modular;
+pub mod params;
+pub mod prop;
+pub mod runner;
+pub mod trace;
+pub mod vector;
+pub mod verify;
+
+use bench::bench;
+use crosscheck::crosscheck;
+use inputs::{Input, Source};
+use params::Params;
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Params: Params;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Self::Part1, String>;
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Self::Part2, String>;
+
+    /// A simpler, slower way to solve part 1, which `crosscheck` compares `part1` against.
+    fn reference1(
+        _input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Option<Result<Self::Part1, String>> {
+        None
+    }
+
+    /// A simpler, slower way to solve part 2, which `crosscheck` compares `part2` against.
+    fn reference2(
+        _input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Option<Result<Self::Part2, String>> {
+        None
+    }
+}
+
+pub trait Answer: fmt::Display {
+    const NUMERIC: bool;
+}
+
+macro_rules! numeric_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                const NUMERIC: bool = true;
+            }
+        )*
+    };
+}
+
+numeric_answer!(u16, u32, u64, u128, usize, i64);
+
+impl Answer for String {
+    const NUMERIC: bool = false;
+}
+
+impl Answer for &str {
+    const NUMERIC: bool = false;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, expected: &str) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// `fragment` should be a slice of `input`; otherwise its first occurrence is used.
+    pub fn at(input: &str, fragment: &str, expected: &str) -> ParseError {
+        let (line, column) = position(input, fragment);
+
+        ParseError::new(line, column, fragment, expected)
+    }
+
+    pub fn end(input: &str, expected: &str) -> ParseError {
+        ParseError::at(input, &input[input.len()..], expected)
+    }
+
+    /// Rebases an error reported relative to `fragment` onto the enclosing `input`.
+    pub fn within(self, input: &str, fragment: &str) -> ParseError {
+        let (line, column) = position(input, fragment);
+
+        ParseError {
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            line: line + self.line - 1,
+            ..self
+        }
+    }
+
+    pub fn render(&self, input: &str) -> String {
+        let source = inputs::lines(input)
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or("");
+        let indent = source.chars().take(self.column - 1).count();
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map(|text| text.chars().count())
+            .unwrap_or(0)
+            .min(source.chars().count().saturating_sub(indent))
+            .max(1);
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        format!(
+            "{}\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            source,
+            gutter,
+            " ".repeat(indent),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.text)
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+fn position(input: &str, fragment: &str) -> (usize, usize) {
+    let start = input.as_ptr() as usize;
+    let ptr = fragment.as_ptr() as usize;
+    let offset = if ptr >= start && ptr + fragment.len() <= start + input.len() {
+        ptr - start
+    } else {
+        input.find(fragment).unwrap_or(0)
+    };
+
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+    (
+        before.matches('\n').count() + 1,
+        inputs::strip_bom(&before[line_start..]).chars().count() + 1,
+    )
+}
+
+pub fn parse_field<T: FromStr>(s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::at(s, s, expected))
+}
+
+pub fn parse_lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    inputs::lines(input)
+        .map(|line| f(line).map_err(|e| e.within(input, line)))
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    pub part: Option<u8>,
+    pub lenient: bool,
+    /// `key=value` overrides applied, in order, to the day's default `Params`.
+    pub params: Vec<(String, String)>,
+    /// Collects `trace` events while solving, to be printed in this format.
+    pub explain: Option<Format>,
+    /// Saves long simulations to this directory and resumes them from it.
+    pub checkpoint: Option<PathBuf>,
+    pub progress: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartAnswer {
+    pub part: u8,
+    pub answer: String,
+    pub numeric: bool,
+    pub elapsed: Duration,
+    pub trace: Vec<trace::Event>,
+}
+
+impl PartAnswer {
+    fn solve<A, F>(part: u8, explain: bool, f: F) -> Result<PartAnswer, String>
+    where
+        A: Answer,
+        F: FnOnce() -> Result<A, String>,
+    {
+        let start = Instant::now();
+        let (answer, trace) = if explain {
+            trace::capture(f)
+        } else {
+            (f(), Vec::new())
+        };
+        let elapsed = start.elapsed();
+
+        Ok(PartAnswer {
+            part,
+            answer: answer?.to_string(),
+            numeric: A::NUMERIC,
+            elapsed,
+            trace,
+        })
+    }
+
+    pub fn to_json(&self, day: u8, warnings: &[ParseError]) -> String {
+        let warnings: Vec<String> = warnings.iter().map(ParseError::to_string).collect();
+        json::object(&self.json_fields(day, &warnings))
+    }
+
+    fn json_fields(&self, day: u8, warnings: &[String]) -> Vec<(&'static str, String)> {
+        vec![
+            ("day", day.to_string()),
+            ("part", self.part.to_string()),
+            (
+                "answer",
+                if self.numeric {
+                    self.answer.clone()
+                } else {
+                    json::string(&self.answer)
+                },
+            ),
+            (
+                "elapsed_ms",
+                format!("{:.3}", self.elapsed.as_secs_f64() * 1000.0),
+            ),
+            (
+                "warnings",
+                json::array(warnings.iter().map(|w| json::string(w))),
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub parts: Vec<PartAnswer>,
+    pub skipped: Vec<ParseError>,
+}
+
+pub fn solve<S: Solution>(input: &str, options: &Options) -> Result<Answers, String> {
+    if let Some(part) = options.part {
+        if part != 1 && part != 2 {
+            return Err(format!("invalid part: {}", part));
+        }
+    }
+
+    let params = params::build::<S::Params>(&options.params)?;
+
+    let (parsed, skipped) = if options.lenient {
+        parse_lenient::<S>(input)
+    } else {
+        S::parse(input).map(|parsed| (parsed, Vec::new()))
+    }
+    .map_err(|e| e.render(input))?;
+
+    let explain = options.explain.is_some();
+    let solve_parts = || {
+        let mut parts = Vec::with_capacity(2);
+
+        if options.part.unwrap_or(1) == 1 {
+            parts.push(PartAnswer::solve(1, explain, || {
+                S::part1(&parsed, &params)
+            })?);
+        }
+
+        if options.part.unwrap_or(2) == 2 {
+            parts.push(PartAnswer::solve(2, explain, || {
+                S::part2(&parsed, &params)
+            })?);
+        }
+
+        Ok::<_, String>(parts)
+    };
+
+    let parts = if options.checkpoint.is_some() || options.progress {
+        let settings = checkpoint::Settings {
+            dir: options.checkpoint.clone(),
+            progress: options.progress,
+            ..checkpoint::Settings::default()
+        };
+        checkpoint::with(settings, solve_parts)?
+    } else {
+        solve_parts()?
+    };
+
+    Ok(Answers { parts, skipped })
+}
+
+/// Drops each line the parser rejects and retries, returning the errors for what was skipped.
+pub fn parse_lenient<S: Solution>(input: &str) -> Result<(S::Input, Vec<ParseError>), ParseError> {
+    let mut lines: Vec<(usize, &str)> = inputs::lines(input).enumerate().collect();
+    let mut skipped = Vec::new();
+
+    loop {
+        let mut remaining = lines
+            .iter()
+            .map(|(_, line)| *line)
+            .collect::<Vec<_>>()
+            .join("\n");
+        if input.ends_with('\n') {
+            remaining.push('\n');
+        }
+
+        match S::parse(&remaining) {
+            Ok(parsed) => return Ok((parsed, skipped)),
+            Err(e) => {
+                let index = e.line.saturating_sub(1);
+                if index >= lines.len() || lines.len() == 1 {
+                    return Err(match lines.get(index) {
+                        Some(&(i, _)) => ParseError { line: i + 1, ..e },
+                        None => ParseError::end(input, &e.expected),
+                    });
+                }
+
+                let (i, _) = lines.remove(index);
+                skipped.push(ParseError { line: i + 1, ..e });
+            }
+        }
+    }
+}
+
+macro_rules! dispatch_day {
+    ($day:expr, $f:ident($($arg:expr),*)) => {{
+        use days::*;
+
+        match $day {
+            1 => $f::<day01::Day01>($($arg),*),
+            2 => $f::<day02::Day02>($($arg),*),
+            3 => $f::<day03::Day03>($($arg),*),
+            4 => $f::<day04::Day04>($($arg),*),
+            5 => $f::<day05::Day05>($($arg),*),
+            6 => $f::<day06::Day06>($($arg),*),
+            7 => $f::<day07::Day07>($($arg),*),
+            8 => $f::<day08::Day08>($($arg),*),
+            9 => $f::<day09::Day09>($($arg),*),
+            10 => $f::<day10::Day10>($($arg),*),
+            11 => $f::<day11::Day11>($($arg),*),
+            12 => $f::<day12::Day12>($($arg),*),
+            13 => $f::<day13::Day13>($($arg),*),
+            14 => $f::<day14::Day14>($($arg),*),
+            15 => $f::<day15::Day15>($($arg),*),
+            16 => $f::<day16::Day16>($($arg),*),
+            17 => $f::<day17::Day17>($($arg),*),
+            18 => $f::<day18::Day18>($($arg),*),
+            19 => $f::<day19::Day19>($($arg),*),
+            20 => $f::<day20::Day20>($($arg),*),
+            21 => $f::<day21::Day21>($($arg),*),
+            22 => $f::<day22::Day22>($($arg),*),
+            23 => $f::<day23::Day23>($($arg),*),
+            24 => $f::<day24::Day24>($($arg),*),
+            25 => $f::<day25::Day25>($($arg),*),
+            x => Err(format!("invalid day: {}", x)),
+        }
+    }};
+}
+
+pub fn solve_day(day: u8, input: &str, options: &Options) -> Result<Answers, String> {
+    dispatch_day!(day, solve(input, options))
+}
+
+pub fn bench_day(day: u8, input: &str, runs: usize) -> Result<Vec<bench::Timing>, String> {
+    dispatch_day!(day, bench(input, runs))
+}
+
+/// Runs the day's solver and its reference solver on `input`, or returns `None` if it has none.
+pub fn crosscheck_day(
+    day: u8,
+    input: &str,
+    params: &[(String, String)],
+) -> Result<Option<Vec<crosscheck::Disagreement>>, String> {
+    dispatch_day!(day, crosscheck(input, params))
+}
+
+pub const DAYS: std::ops::RangeInclusive<u8> = 1..=25;
+
+/// Entry point for the per-day binaries: `[--lenient] [--explain[=FORMAT]] [--progress]
+/// [--checkpoint DIR] [--param KEY=VALUE]... [input]...`.
+pub fn run<S: Solution>() {
+    let mut options = Options::default();
+    let mut paths = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lenient" => options.lenient = true,
+            "--progress" => options.progress = true,
+            "--checkpoint" => match args.next() {
+                Some(dir) => options.checkpoint = Some(PathBuf::from(dir)),
+                None => {
+                    eprintln!("--checkpoint needs a directory argument");
+                    process::exit(1);
+                }
+            },
+            "--param" => match args.next().as_deref().map(params::parse_override) {
+                Some(Ok((day, key, value))) => {
+                    if day.is_none_or(|day| day == S::DAY) {
+                        options.params.push((key, value));
+                    }
+                }
+                Some(Err(e)) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+                None => {
+                    eprintln!("--param needs a key=value argument");
+                    process::exit(1);
+                }
+            },
+            x if x.starts_with("--") => match trace::parse_flag(x) {
+                Some(Ok(format)) => options.explain = Some(format),
+                Some(Err(e)) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+                None => {
+                    eprintln!("unexpected argument: {}", x);
+                    process::exit(1);
+                }
+            },
+            path => paths.push(PathBuf::from(path)),
+        }
+    }
+
+    let result = inputs::resolve(S::DAY, &paths, Path::new(inputs::CACHE_DIR))
+        .and_then(|sources| solve_inputs(S::DAY, &sources, &options, Format::Text));
+
+    if let Err(e) = result {
+        eprintln!("Day {}: {}", S::DAY, e);
+        process::exit(1);
+    }
+}
+
+/// Solves `day` for each source and prints the answers, labelled by source when there is
+/// more than one. Failures are reported as they happen so the remaining sources still run.
+pub fn solve_inputs(
+    day: u8,
+    sources: &[Source],
+    options: &Options,
+    format: Format,
+) -> Result<(), String> {
+    let labelled = sources.len() > 1;
+    let mut failed = 0;
+
+    for source in sources {
+        let result = inputs::load(source.clone()).and_then(|input| {
+            if let Some(warning) = input.warning {
+                eprintln!("warning: {}: {}", input.source, warning);
+            }
+
+            Ok((solve_day(day, &input.text, options)?, input))
+        });
+
+        match result {
+            Ok((answers, input)) => {
+                if let Some(explain) = options.explain {
+                    print_trace(day, &answers, explain);
+                }
+
+                print_answers(day, &answers, &input, labelled, format);
+            }
+            Err(e) if !labelled => return Err(e),
+            Err(e) => {
+                failed += 1;
+                eprintln!("Day {} ({}): {}", day, source, e);
+            }
+        }
+    }
+
+    if failed > 0 {
+        Err(format!("{} of {} inputs failed", failed, sources.len()))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            x => Err(format!("invalid format: {}", x)),
+        }
+    }
+}
+
+/// Prints the answers, preceded by a `==> source <==` header (or an `input` field in JSON) when
+/// `labelled`. JSON output also carries the input's warning, which callers print to stderr.
+pub fn print_answers(day: u8, answers: &Answers, input: &Input, labelled: bool, format: Format) {
+    match format {
+        Format::Text => {
+            if labelled {
+                println!("==> {} <==", input.source);
+            }
+
+            for skipped in &answers.skipped {
+                eprintln!("warning: skipped {}", skipped.render(&input.text));
+            }
+
+            for part in &answers.parts {
+                println!("Part {}: {}", part.part, part.answer);
+            }
+        }
+        Format::Json => {
+            let warnings: Vec<String> = input
+                .warning
+                .iter()
+                .map(ToString::to_string)
+                .chain(answers.skipped.iter().map(ParseError::to_string))
+                .collect();
+
+            for part in &answers.parts {
+                let mut fields = part.json_fields(day, &warnings);
+                if labelled {
+                    fields.insert(1, ("input", json::string(&input.source.to_string())));
+                }
+
+                println!("{}", json::object(&fields));
+            }
+        }
+    }
+}
+
+/// Prints the events each part emitted to stderr, keeping stdout to the answers.
+pub fn print_trace(day: u8, answers: &Answers, format: Format) {
+    for part in &answers.parts {
+        for event in &part.trace {
+            match format {
+                Format::Text => eprintln!("day {} part {}: {}", day, part.part, event),
+                Format::Json => eprintln!("{}", event.to_json(day, part.part)),
+            }
+        }
+    }
+}
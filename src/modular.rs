@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+pub fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+pub fn modpow(base: u64, exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+pub fn inverse(a: u64, m: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(a as i128, m as i128);
+
+    if g == 1 {
+        Some(x.rem_euclid(m as i128) as u64)
+    } else {
+        None
+    }
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, returning the
+/// smallest such `x` and the combined modulus. The moduli need not be coprime.
+pub fn crt(congruences: &[(u64, u64)]) -> Result<(u128, u128), String> {
+    let mut x: u128 = 0;
+    let mut modulus: u128 = 1;
+
+    for &(residue, m) in congruences {
+        if m == 0 {
+            return Err("modulus must be positive".to_string());
+        }
+
+        // In 128 bits, since `residue % m + m` can overflow 64.
+        let g = gcd(modulus, m as u128);
+        let diff = ((residue % m) as u128 + m as u128 - x % m as u128) % m as u128;
+        if !diff.is_multiple_of(g) {
+            return Err(format!("no solution for residue {} modulo {}", residue, m));
+        }
+
+        let step = m / g as u64;
+        let inv = inverse(((modulus / g) % step as u128) as u64, step)
+            .expect("coprime once the gcd is divided out");
+        let k = mul_mod((diff / g) as u64, inv, step);
+
+        let combined = lcm(modulus, m as u128)
+            .ok_or_else(|| "combined modulus overflows 128 bits".to_string())?;
+        x = (x + modulus * k as u128) % combined;
+        modulus = combined;
+    }
+
+    Ok((x, modulus))
+}
+
+/// Baby-step/giant-step search for the smallest `x` with `base^x ≡ target (mod m)`.
+/// `base` must be coprime with `m`.
+pub fn discrete_log(base: u64, target: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+
+    let target = target % m;
+    let mut n = (m as f64).sqrt() as u64;
+    while (n as u128) * (n as u128) < m as u128 {
+        n += 1;
+    }
+
+    let mut baby_steps: HashMap<u64, u64> = HashMap::new();
+    let mut power = 1 % m;
+    for j in 0..n {
+        baby_steps.entry(power).or_insert(j);
+        power = mul_mod(power, base, m);
+    }
+
+    let giant_step = inverse(modpow(base, n, m), m)?;
+    let mut gamma = target;
+    for i in 0..n {
+        if let Some(&j) = baby_steps.get(&gamma) {
+            return Some(i * n + j);
+        }
+        gamma = mul_mod(gamma, giant_step, m);
+    }
+
+    None
+}
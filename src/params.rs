@@ -0,0 +1,181 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use toml::Value;
+
+pub trait Params: Default {
+    const KEYS: &'static [&'static str];
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+}
+
+impl Params for () {
+    const KEYS: &'static [&'static str] = &[];
+
+    fn set(&mut self, key: &str, _: &str) -> Result<(), String> {
+        Err(unknown(key, Self::KEYS))
+    }
+}
+
+pub fn unknown(key: &str, keys: &[&str]) -> String {
+    if keys.is_empty() {
+        format!("unknown parameter `{}` (this day has no parameters)", key)
+    } else {
+        format!(
+            "unknown parameter `{}` (expected one of: {})",
+            key,
+            keys.join(", ")
+        )
+    }
+}
+
+/// Declares a day's `Params` struct with defaults, settable by field name from strings.
+macro_rules! day_params {
+    ($($name:ident: $t:ty = $default:expr),* $(,)?) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct Params {
+            $(pub $name: $t,)*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Params {
+                    $($name: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for Params {
+            const KEYS: &'static [&'static str] = &[$(stringify!($name)),*];
+
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(stringify!($name) => {
+                        self.$name = value
+                            .parse()
+                            .map_err(|_| format!("invalid value for `{}`: {}", key, value))?;
+                    })*
+                    _ => return Err($crate::params::unknown(key, Self::KEYS)),
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use day_params;
+
+/// A comma-separated list parameter, such as `1:1,3:1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct List<T>(pub Vec<T>);
+
+impl<T: FromStr> FromStr for List<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(|item| item.trim().parse())
+            .collect::<Result<_, _>>()
+            .map(List)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", item)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn build<P: Params>(overrides: &[(String, String)]) -> Result<P, String> {
+    let mut params = P::default();
+
+    for (key, value) in overrides {
+        params.set(key, value)?;
+    }
+
+    Ok(params)
+}
+
+/// Splits `key=value` or `dayN.key=value` into an optional day, the key and the value.
+pub fn parse_override(s: &str) -> Result<(Option<u8>, String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid parameter: {} (expected key=value)", s))?;
+
+    let (day, key) = match key.split_once('.') {
+        Some((day, key)) => {
+            let day = day
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("invalid parameter day: {}", day))?;
+            (Some(day), key)
+        }
+        None => (None, key),
+    };
+
+    Ok((day, key.trim().to_string(), value.trim().to_string()))
+}
+
+/// Reads `[dayN]` tables of parameter overrides from an `aoc.toml` file.
+pub fn parse_config(toml: &str) -> Result<BTreeMap<u8, Vec<(String, String)>>, String> {
+    let root: Value = toml
+        .parse()
+        .map_err(|e| format!("invalid config file: {}", e))?;
+    let tables = root
+        .as_table()
+        .ok_or_else(|| "invalid config file: expected [dayN] tables".to_string())?;
+
+    let mut config = BTreeMap::new();
+
+    for (name, table) in tables {
+        // The only top-level setting, read by `client::session`.
+        if name == "session" {
+            continue;
+        }
+
+        let context = |msg: &str| format!("invalid config file: [{}] {}", name, msg);
+
+        let day: u8 = name
+            .strip_prefix("day")
+            .and_then(|day| day.parse().ok())
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| context("is not a table named day1 to day25"))?;
+        let table = table.as_table().ok_or_else(|| context("must be a table"))?;
+
+        let overrides = table
+            .iter()
+            .map(|(key, value)| {
+                config_value(value)
+                    .map(|value| (key.clone(), value))
+                    .ok_or_else(|| context(&format!("`{}` must be a string, number or array", key)))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        config.insert(day, overrides);
+    }
+
+    Ok(config)
+}
+
+fn config_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Integer(n) => Some(n.to_string()),
+        Value::Float(n) => Some(n.to_string()),
+        Value::Boolean(b) => Some(b.to_string()),
+        Value::Array(items) => items
+            .iter()
+            .map(config_value)
+            .collect::<Option<Vec<_>>>()
+            .map(|items| items.join(",")),
+        _ => None,
+    }
+}
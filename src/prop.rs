@@ -0,0 +1,187 @@
+use std::{
+    env, fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::gen::Rng;
+
+/// Cases each property runs under a plain `cargo test`.
+pub const CASES: usize = 200;
+/// Overrides `CASES`, to fuzz for longer.
+pub const CASES_VAR: &str = "AOC_PROP_CASES";
+/// Overrides the fixed seed, to explore other cases or replay a reported failure.
+pub const SEED_VAR: &str = "AOC_PROP_SEED";
+
+const SEED: u64 = 2020;
+
+fn env_or<T: std::str::FromStr>(var: &str, default: T) -> T {
+    env::var(var)
+        .ok()
+        .and_then(|val| val.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Runs `property` on values from `generate`, panicking with the smallest failing value that
+/// `shrink` leads to if any case fails or panics. Each property draws from its own stream, seeded
+/// from its name, so adding a property does not change the cases of the others.
+pub fn check<T, G, S, P>(name: &str, mut generate: G, shrink: S, property: P)
+where
+    T: fmt::Debug,
+    G: FnMut(&mut Rng) -> T,
+    S: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Result<(), String>,
+{
+    let seed = env_or(SEED_VAR, SEED);
+    let rng = &mut Rng::new(name.bytes().fold(seed, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    }));
+
+    for case in 0..env_or(CASES_VAR, CASES) {
+        let value = generate(rng);
+        if let Err(e) = run(&property, &value) {
+            let (value, e) = minimise(value, e, &shrink, &property);
+            panic!(
+                "property `{}` failed on case {} with {}={}: {}\nminimal case: {:?}",
+                name, case, SEED_VAR, seed, e, value
+            );
+        }
+    }
+}
+
+/// A panic counts as a failure, with its message as the error.
+fn run<T, P: Fn(&T) -> Result<(), String>>(property: &P, value: &T) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| property(value))).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(format!("panicked: {}", message))
+    })
+}
+
+/// Takes the first smaller value that still fails, until none does.
+fn minimise<T, S, P>(mut value: T, mut error: String, shrink: &S, property: &P) -> (T, String)
+where
+    S: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Result<(), String>,
+{
+    'shrinking: loop {
+        for candidate in shrink(&value) {
+            if let Err(e) = run(property, &candidate) {
+                value = candidate;
+                error = e;
+                continue 'shrinking;
+            }
+        }
+
+        return (value, error);
+    }
+}
+
+/// For values with nothing smaller worth trying.
+pub fn no_shrink<T>(_: &T) -> Vec<T> {
+    Vec::new()
+}
+
+/// Shorter vectors: halves first, then ever smaller runs removed from each position.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let end = (start + chunk).min(items.len());
+            candidates.push([&items[..start], &items[end..]].concat());
+        }
+        chunk /= 2;
+    }
+    if items.len() == 1 {
+        candidates.push(Vec::new());
+    }
+
+    candidates
+}
+
+/// Shorter strings, removing whole lines before single characters.
+pub fn shrink_str(s: &str) -> Vec<String> {
+    let lines: Vec<&str> = s.split_inclusive('\n').collect();
+    let chars: Vec<char> = s.chars().collect();
+
+    let mut candidates: Vec<String> = if lines.len() > 1 {
+        shrink_vec(&lines)
+            .iter()
+            .map(|lines| lines.concat())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    candidates.extend(
+        shrink_vec(&chars)
+            .into_iter()
+            .map(|chars| chars.into_iter().collect()),
+    );
+
+    candidates
+}
+
+/// Fragments that tend to trip parsers: separators, signs, huge numbers, stray unicode and
+/// line endings.
+const FRAGMENTS: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    "\r\n",
+    "\t",
+    "0",
+    "1",
+    "9",
+    "-",
+    "+",
+    "-0",
+    "x",
+    "X",
+    ":",
+    ",",
+    ".",
+    "|",
+    "\"",
+    "=",
+    "[",
+    "]",
+    "a",
+    "é",
+    "\u{feff}",
+    "99999999999999999999",
+    "contain",
+    "bags",
+    "or",
+    "mask = ",
+    "mem[",
+];
+
+/// Applies a few random edits to `s`: deleting, inserting, duplicating or truncating text.
+pub fn mutate(rng: &mut Rng, s: &str) -> String {
+    let mut chars: Vec<char> = s.chars().collect();
+
+    for _ in 0..rng.range(1..=4) {
+        let at = rng.below(chars.len() + 1);
+        match rng.below(8) {
+            0 | 1 if at < chars.len() => {
+                let end = (at + 1 + rng.below(8)).min(chars.len());
+                chars.drain(at..end);
+            }
+            2 | 3 => {
+                let len = rng.below((chars.len() - at).min(16) + 1);
+                let copy: Vec<char> = chars[at..at + len].to_vec();
+                chars.splice(at..at, copy);
+            }
+            4 => chars.truncate(at),
+            _ => {
+                let fragment = rng.choose(FRAGMENTS);
+                chars.splice(at..at, fragment.chars());
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
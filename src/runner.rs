@@ -0,0 +1,327 @@
+use std::{
+    any::Any,
+    collections::VecDeque,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Condvar, Mutex, PoisonError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    inputs::{self, Input, Source},
+    solve_day,
+    verify::KnownAnswers,
+    Answers, Options,
+};
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub day: u8,
+    pub source: Source,
+    pub options: Options,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Error(String),
+    Panic(String),
+    Timeout(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(e) => write!(f, "{}", e),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+            Failure::Timeout(timeout) => write!(f, "timed out after {:?}", timeout),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finished {
+    pub job: Job,
+    pub result: Result<(Input, Answers), Failure>,
+    pub elapsed: Duration,
+}
+
+/// Runs `jobs` on `threads` worker threads and returns their results in the same order.
+///
+/// Each job runs on its own thread so that a panic is contained and a job that exceeds
+/// `timeout` can be abandoned. Std threads cannot be killed, so an abandoned job keeps its
+/// share of `threads` until it finishes, and no more than `threads` solvers ever run at once.
+pub fn run(jobs: Vec<Job>, threads: usize, timeout: Duration) -> Vec<Finished> {
+    let total = jobs.len();
+    let threads = threads.clamp(1, total.max(1));
+    let queue = Arc::new(Mutex::new(
+        jobs.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let slots = Arc::new(Slots {
+        free: Mutex::new(threads),
+        released: Condvar::new(),
+    });
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..threads {
+        let queue = Arc::clone(&queue);
+        let slots = Arc::clone(&slots);
+        let sender = sender.clone();
+
+        thread::spawn(move || loop {
+            let next = queue.lock().map(|mut queue| queue.pop_front());
+            let (index, job) = match next {
+                Ok(Some(next)) => next,
+                _ => break,
+            };
+
+            let finished = run_isolated(job, Slots::acquire(&slots), timeout);
+            if sender.send((index, finished)).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+
+    let mut finished: Vec<Option<Finished>> = vec![None; total];
+    for (index, result) in receiver {
+        finished[index] = Some(result);
+    }
+
+    finished
+        .into_iter()
+        .map(|finished| finished.expect("every job reports back"))
+        .collect()
+}
+
+/// Counts the solver threads that may still start, including those abandoned ones that
+/// have not yet finished.
+struct Slots {
+    free: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Slots {
+    /// Waits for a free slot, held until the returned `Slot` is dropped.
+    fn acquire(slots: &Arc<Slots>) -> Slot {
+        let free = slots.free.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut free = slots
+            .released
+            .wait_while(free, |free| *free == 0)
+            .unwrap_or_else(PoisonError::into_inner);
+        *free -= 1;
+
+        Slot(Arc::clone(slots))
+    }
+}
+
+struct Slot(Arc<Slots>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *self.0.free.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+        self.0.released.notify_one();
+    }
+}
+
+/// Runs `job` on a thread of its own, which keeps `slot` until the solver returns, even once
+/// the job has been abandoned.
+fn run_isolated(job: Job, slot: Slot, timeout: Duration) -> Finished {
+    let (sender, receiver) = mpsc::channel();
+    let start = Instant::now();
+
+    let spawned = {
+        let job = job.clone();
+        thread::Builder::new()
+            .name(format!("day {}", job.day))
+            .spawn(move || {
+                let _slot = slot;
+                let result = panic::catch_unwind(AssertUnwindSafe(|| solve(&job)));
+                let _ = sender.send(result);
+            })
+    };
+
+    let result = match spawned {
+        Err(e) => Err(Failure::Error(format!("failed to start solver: {}", e))),
+        Ok(_) => match receiver.recv_timeout(timeout) {
+            Ok(Ok(result)) => result,
+            Ok(Err(payload)) => Err(Failure::Panic(panic_message(payload))),
+            Err(RecvTimeoutError::Timeout) => Err(Failure::Timeout(timeout)),
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(Failure::Panic("solver exited without a result".to_string()))
+            }
+        },
+    };
+
+    Finished {
+        job,
+        result,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn solve(job: &Job) -> Result<(Input, Answers), Failure> {
+    let input = inputs::load(job.source.clone()).map_err(Failure::Error)?;
+    let answers = solve_day(job.day, &input.text, &job.options).map_err(Failure::Error)?;
+
+    Ok((input, answers))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .unwrap_or_else(|| "unknown panic".to_string()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Mismatch { expected: String },
+    Timeout,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Mismatch { expected } => write!(f, "mismatch (expected {})", expected),
+            Status::Timeout => write!(f, "timeout"),
+            Status::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// One line of the summary: a solved part, or a whole day that failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub source: Source,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub status: Status,
+}
+
+/// Flattens results into rows, checking answers against `known` where it has them.
+pub fn rows(finished: &[Finished], known: &[KnownAnswers]) -> Vec<Row> {
+    let mut rows = Vec::new();
+
+    for finished in finished {
+        let job = &finished.job;
+        let expected = |part| {
+            known
+                .iter()
+                .find(|known| {
+                    known.day == job.day && Source::File(known.input.clone()) == job.source
+                })
+                .and_then(|known| known.part(part))
+        };
+
+        match &finished.result {
+            Ok((_, answers)) => rows.extend(answers.parts.iter().map(|part| Row {
+                day: job.day,
+                source: job.source.clone(),
+                part: Some(part.part),
+                answer: Some(part.answer.clone()),
+                elapsed: part.elapsed,
+                status: match expected(part.part) {
+                    Some(expected) if expected != part.answer => Status::Mismatch {
+                        expected: expected.to_string(),
+                    },
+                    _ => Status::Ok,
+                },
+            })),
+            Err(failure) => rows.push(Row {
+                day: job.day,
+                source: job.source.clone(),
+                part: None,
+                answer: None,
+                elapsed: finished.elapsed,
+                status: match failure {
+                    Failure::Timeout(_) => Status::Timeout,
+                    _ => Status::Error,
+                },
+            }),
+        }
+    }
+
+    rows
+}
+
+/// Renders rows as an aligned table followed by a totals line. `wall` is the real time the
+/// whole run took, which parallelism makes shorter than the summed solve times.
+pub fn table(rows: &[Row], wall: Duration, with_source: bool) -> String {
+    let mut header = vec!["Day", "Part", "Answer", "Time", "Status"];
+    if with_source {
+        header.insert(1, "Input");
+    }
+
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            let mut cells = vec![
+                row.day.to_string(),
+                row.part.map_or("-".to_string(), |part| part.to_string()),
+                row.answer.clone().unwrap_or_else(|| "-".to_string()),
+                format!("{:.1?}", row.elapsed),
+                row.status.to_string(),
+            ];
+            if with_source {
+                cells.insert(1, row.source.to_string());
+            }
+            cells
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain(Some(header[i].len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut table = line(header);
+    table.push('\n');
+    for row in &cells {
+        table.push_str(&line(row.iter().map(String::as_str).collect()));
+        table.push('\n');
+    }
+
+    let count = |f: fn(&Status) -> bool| rows.iter().filter(|row| f(&row.status)).count();
+    table.push_str(&format!(
+        "Total: {} ok, {} mismatch, {} timeout, {} error in {:.1?} ({:.1?} wall)",
+        count(|s| *s == Status::Ok),
+        count(|s| matches!(s, Status::Mismatch { .. })),
+        count(|s| *s == Status::Timeout),
+        count(|s| *s == Status::Error),
+        rows.iter().map(|row| row.elapsed).sum::<Duration>(),
+        wall
+    ));
+
+    table
+}
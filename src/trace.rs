@@ -0,0 +1,127 @@
+use std::{cell::RefCell, fmt};
+
+use crate::{json, Answer, Format};
+
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+/// A step a solver took on the way to its answer, such as which instruction it patched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub key: &'static str,
+    pub value: String,
+    pub numeric: bool,
+}
+
+impl Event {
+    pub fn new(name: &'static str) -> Event {
+        Event {
+            name,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn with<T: Answer>(mut self, key: &'static str, value: T) -> Event {
+        self.fields.push(Field {
+            key,
+            value: value.to_string(),
+            numeric: T::NUMERIC,
+        });
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.key == key)
+            .map(|field| field.value.as_str())
+    }
+
+    pub fn to_json(&self, day: u8, part: u8) -> String {
+        let mut fields = vec![
+            ("day", day.to_string()),
+            ("part", part.to_string()),
+            ("event", json::string(self.name)),
+        ];
+        fields.extend(self.fields.iter().map(|field| {
+            (
+                field.key,
+                if field.numeric {
+                    field.value.clone()
+                } else {
+                    json::string(&field.value)
+                },
+            )
+        }));
+
+        json::object(&fields)
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        for field in &self.fields {
+            if field.value.is_empty() || field.value.contains(char::is_whitespace) {
+                write!(f, " {}={:?}", field.key, field.value)?;
+            } else {
+                write!(f, " {}={}", field.key, field.value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Records the event built by `event` if a `capture` is active on this thread. Otherwise
+/// `event` is never called, so tracing costs a thread-local lookup when it is off.
+pub fn emit<F: FnOnce() -> Event>(event: F) {
+    if !enabled() {
+        return;
+    }
+
+    let event = event();
+    EVENTS.with(|events| {
+        if let Some(events) = events.borrow_mut().as_mut() {
+            events.push(event);
+        }
+    });
+}
+
+pub fn enabled() -> bool {
+    EVENTS.with(|events| events.borrow().is_some())
+}
+
+/// Runs `f`, returning what it produced along with every event it emitted.
+pub fn capture<T, F: FnOnce() -> T>(f: F) -> (T, Vec<Event>) {
+    let _outer = Restore(EVENTS.with(|events| events.replace(Some(Vec::new()))));
+    let result = f();
+    let events = EVENTS.with(|events| events.borrow_mut().take());
+
+    (result, events.unwrap_or_default())
+}
+
+/// Puts back the events `capture` set aside, even if its closure panics.
+struct Restore(Option<Vec<Event>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        EVENTS.with(|events| events.replace(self.0.take()));
+    }
+}
+
+/// Parses `--explain` or `--explain=FORMAT`, returning `None` for any other argument.
+pub fn parse_flag(arg: &str) -> Option<Result<Format, String>> {
+    match arg.strip_prefix("--explain")? {
+        "" => Some(Ok(Format::Text)),
+        format => format.strip_prefix('=').map(str::parse),
+    }
+}
@@ -0,0 +1,172 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on an `N`-dimensional integer lattice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<const N: usize>(pub [isize; N]);
+
+impl<const N: usize> Vector<N> {
+    pub const ZERO: Vector<N> = Vector([0; N]);
+
+    /// The sum of the absolute components: steps along the axes to reach this from the origin.
+    pub fn manhattan(&self) -> usize {
+        self.0.iter().map(|c| c.unsigned_abs()).sum()
+    }
+
+    /// The largest absolute component: king's moves to reach this from the origin.
+    pub fn chebyshev(&self) -> usize {
+        self.0.iter().map(|c| c.unsigned_abs()).max().unwrap_or(0)
+    }
+
+    /// The `3^N - 1` offsets to cells touching this one, even at a corner.
+    pub fn moore_offsets() -> Vec<Vector<N>> {
+        let mut offsets = vec![Vector::ZERO];
+
+        for axis in 0..N {
+            offsets = offsets
+                .into_iter()
+                .flat_map(|offset| {
+                    (-1..=1).map(move |d| {
+                        let mut offset = offset;
+                        offset.0[axis] += d;
+                        offset
+                    })
+                })
+                .collect();
+        }
+
+        offsets.retain(|&offset| offset != Vector::ZERO);
+        offsets
+    }
+
+    /// The `2N` offsets to cells sharing a face with this one.
+    pub fn orthogonal_offsets() -> Vec<Vector<N>> {
+        (0..N)
+            .flat_map(|axis| {
+                [-1, 1].iter().map(move |&d| {
+                    let mut offset = Vector::ZERO;
+                    offset.0[axis] = d;
+                    offset
+                })
+            })
+            .collect()
+    }
+
+    /// Moves `point` by this offset, or `None` if it would leave the unsigned range.
+    pub fn offset(&self, point: [usize; N]) -> Option<[usize; N]> {
+        let mut moved = point;
+        for (c, d) in moved.iter_mut().zip(&self.0) {
+            *c = c.checked_add_signed(*d)?;
+        }
+
+        Some(moved)
+    }
+}
+
+impl Vector<2> {
+    /// Turns a quarter turn anticlockwise `quarter_turns` times, with `y` pointing up.
+    pub fn turn_left(self, quarter_turns: isize) -> Vector<2> {
+        let Vector([x, y]) = self;
+        match quarter_turns.rem_euclid(4) {
+            0 => Vector([x, y]),
+            1 => Vector([-y, x]),
+            2 => Vector([-x, -y]),
+            _ => Vector([y, -x]),
+        }
+    }
+
+    pub fn turn_right(self, quarter_turns: isize) -> Vector<2> {
+        self.turn_left(-quarter_turns)
+    }
+
+    /// Rotates anticlockwise by `degrees`. Multiples of 90 are exact; any other angle lands on
+    /// the nearest lattice point.
+    pub fn rotate(self, degrees: isize) -> Vector<2> {
+        if degrees % 90 == 0 {
+            return self.turn_left(degrees / 90);
+        }
+
+        let (sin, cos) = (degrees as f64).to_radians().sin_cos();
+        let Vector([x, y]) = self;
+        let (x, y) = (x as f64, y as f64);
+        Vector([
+            (x * cos - y * sin).round() as isize,
+            (x * sin + y * cos).round() as isize,
+        ])
+    }
+}
+
+/// Hex steps in doubled coordinates, where east is `(2, 0)` and north-east `(1, 1)`: east,
+/// south-east, south-west, west, north-west and north-east.
+pub const HEX_OFFSETS: [Vector<2>; 6] = [
+    Vector([2, 0]),
+    Vector([1, -1]),
+    Vector([-1, -1]),
+    Vector([-2, 0]),
+    Vector([-1, 1]),
+    Vector([1, 1]),
+];
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Vector::ZERO
+    }
+}
+
+impl<const N: usize> From<[isize; N]> for Vector<N> {
+    fn from(coords: [isize; N]) -> Self {
+        Vector(coords)
+    }
+}
+
+impl<const N: usize> Add for Vector<N> {
+    type Output = Vector<N>;
+
+    fn add(mut self, rhs: Vector<N>) -> Vector<N> {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign for Vector<N> {
+    fn add_assign(&mut self, rhs: Vector<N>) {
+        for (c, d) in self.0.iter_mut().zip(&rhs.0) {
+            *c += d;
+        }
+    }
+}
+
+impl<const N: usize> Sub for Vector<N> {
+    type Output = Vector<N>;
+
+    fn sub(mut self, rhs: Vector<N>) -> Vector<N> {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for Vector<N> {
+    fn sub_assign(&mut self, rhs: Vector<N>) {
+        for (c, d) in self.0.iter_mut().zip(&rhs.0) {
+            *c -= d;
+        }
+    }
+}
+
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Vector<N>;
+
+    fn neg(self) -> Vector<N> {
+        self * -1
+    }
+}
+
+impl<const N: usize> Mul<isize> for Vector<N> {
+    type Output = Vector<N>;
+
+    fn mul(mut self, rhs: isize) -> Vector<N> {
+        for c in self.0.iter_mut() {
+            *c *= rhs;
+        }
+        self
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use toml::Value;
+
+use crate::{
+    inputs::{self, Source},
+    solve_day, Options, DAYS,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswers {
+    pub day: u8,
+    pub input: PathBuf,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    /// Answers the site has already rejected, so they are never submitted twice.
+    pub rejected1: Vec<String>,
+    pub rejected2: Vec<String>,
+}
+
+impl KnownAnswers {
+    pub fn new(day: u8, input: impl Into<PathBuf>) -> Self {
+        KnownAnswers {
+            day,
+            input: input.into(),
+            part1: None,
+            part2: None,
+            rejected1: Vec::new(),
+            rejected2: Vec::new(),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set_part(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    pub fn rejected(&self, part: u8) -> &[String] {
+        match part {
+            1 => &self.rejected1,
+            2 => &self.rejected2,
+            _ => &[],
+        }
+    }
+
+    pub fn reject(&mut self, part: u8, answer: String) {
+        let rejected = match part {
+            1 => &mut self.rejected1,
+            2 => &mut self.rejected2,
+            _ => return,
+        };
+
+        if !rejected.contains(&answer) {
+            rejected.push(answer);
+        }
+    }
+}
+
+pub fn parse_answers(toml: &str) -> Result<Vec<KnownAnswers>, String> {
+    let root: Value = toml
+        .parse()
+        .map_err(|e| format!("invalid answers file: {}", e))?;
+
+    let entries = match root.get("answer") {
+        Some(Value::Array(entries)) => entries.as_slice(),
+        Some(_) => {
+            return Err("invalid answers file: `answer` must be [[answer]] tables".to_string())
+        }
+        None => &[],
+    };
+
+    let mut answers: Vec<KnownAnswers> = Vec::new();
+
+    for (i, entry) in entries.iter().enumerate() {
+        let context = |msg: &str| format!("invalid answer #{}: {}", i + 1, msg);
+
+        let day = entry
+            .get("day")
+            .and_then(Value::as_integer)
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| context("`day` must be a number from 1 to 25"))? as u8;
+        let input = entry
+            .get("input")
+            .and_then(Value::as_str)
+            .ok_or_else(|| context("`input` must be a path"))?;
+
+        let mut known = KnownAnswers::new(day, input);
+        for part in 1..=2 {
+            let key = format!("part{}", part);
+            match entry.get(&key) {
+                Some(Value::String(s)) => known.set_part(part, s.clone()),
+                Some(Value::Integer(n)) => known.set_part(part, n.to_string()),
+                Some(_) => return Err(context(&format!("`{}` must be a string or integer", key))),
+                None => {}
+            }
+
+            let key = format!("part{}_rejected", part);
+            match entry.get(&key) {
+                Some(Value::Array(values)) => {
+                    for value in values {
+                        match value {
+                            Value::String(s) => known.reject(part, s.clone()),
+                            Value::Integer(n) => known.reject(part, n.to_string()),
+                            _ => {
+                                return Err(context(&format!(
+                                    "`{}` must be an array of strings or integers",
+                                    key
+                                )))
+                            }
+                        }
+                    }
+                }
+                Some(_) => return Err(context(&format!("`{}` must be an array", key))),
+                None => {}
+            }
+        }
+
+        if answers
+            .iter()
+            .any(|other| other.day == known.day && other.input == known.input)
+        {
+            return Err(context("duplicate day and input"));
+        }
+
+        answers.push(known);
+    }
+
+    Ok(answers)
+}
+
+pub fn to_toml(answers: &[KnownAnswers]) -> String {
+    let mut toml = String::new();
+
+    for known in answers {
+        if !toml.is_empty() {
+            toml.push('\n');
+        }
+
+        toml.push_str("[[answer]]\n");
+        toml.push_str(&format!("day = {}\n", known.day));
+        toml.push_str(&format!(
+            "input = {}\n",
+            Value::String(known.input.display().to_string())
+        ));
+        for part in 1..=2 {
+            if let Some(answer) = known.part(part) {
+                toml.push_str(&format!(
+                    "part{} = {}\n",
+                    part,
+                    Value::String(answer.to_string())
+                ));
+            }
+            if !known.rejected(part).is_empty() {
+                toml.push_str(&format!(
+                    "part{}_rejected = {}\n",
+                    part,
+                    Value::Array(
+                        known
+                            .rejected(part)
+                            .iter()
+                            .map(|answer| Value::String(answer.clone()))
+                            .collect()
+                    )
+                ));
+            }
+        }
+    }
+
+    toml
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Match(String),
+    Mismatch { expected: String, actual: String },
+    New(String),
+    Missing,
+    Failed(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Mismatch { .. } | Status::Failed(_))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub status: Status,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} part {} ({}): ",
+            self.day,
+            self.part,
+            self.input.display()
+        )?;
+
+        match &self.status {
+            Status::Match(answer) => write!(f, "ok {}", answer),
+            Status::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH expected {}, got {}", expected, actual)
+            }
+            Status::New(answer) => write!(f, "new answer {}", answer),
+            Status::Missing => write!(f, "missing answer and input"),
+            Status::Failed(e) => write!(f, "FAILED {}", e),
+        }
+    }
+}
+
+pub fn check(known: &KnownAnswers, input: &str) -> Vec<Check> {
+    let result = solve_day(known.day, input, &Options::default());
+
+    (1..=2)
+        .map(|part| {
+            let status = match &result {
+                Ok(answers) => match answers.parts.iter().find(|a| a.part == part) {
+                    Some(actual) => match known.part(part) {
+                        Some(expected) if expected == actual.answer => {
+                            Status::Match(actual.answer.clone())
+                        }
+                        Some(expected) => Status::Mismatch {
+                            expected: expected.to_string(),
+                            actual: actual.answer.clone(),
+                        },
+                        None => Status::New(actual.answer.clone()),
+                    },
+                    None => Status::Failed("no answer produced".to_string()),
+                },
+                Err(e) => Status::Failed(e.clone()),
+            };
+
+            Check {
+                day: known.day,
+                part,
+                input: known.input.clone(),
+                status,
+            }
+        })
+        .collect()
+}
+
+pub fn verify(answers: &[KnownAnswers], input_dir: &Path) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for day in DAYS {
+        let mut known: Vec<KnownAnswers> = answers
+            .iter()
+            .filter(|known| known.day == day)
+            .cloned()
+            .collect();
+
+        if known.is_empty() {
+            let input = inputs::day_file(input_dir, day);
+            if !input.exists() {
+                checks.extend((1..=2).map(|part| Check {
+                    day,
+                    part,
+                    input: input.clone(),
+                    status: Status::Missing,
+                }));
+                continue;
+            }
+
+            known.push(KnownAnswers::new(day, input));
+        }
+
+        for known in known {
+            match inputs::load(Source::File(known.input.clone())) {
+                Ok(input) => checks.extend(check(&known, &input.text)),
+                Err(e) => checks.extend((1..=2).map(|part| Check {
+                    day,
+                    part,
+                    input: known.input.clone(),
+                    status: Status::Failed(e.clone()),
+                })),
+            }
+        }
+    }
+
+    checks
+}
+
+pub fn record(answers: &mut Vec<KnownAnswers>, checks: &[Check]) -> usize {
+    let mut recorded = 0;
+
+    for check in checks {
+        if let Status::New(answer) = &check.status {
+            let index = match answers
+                .iter()
+                .position(|known| known.day == check.day && known.input == check.input)
+            {
+                Some(index) => index,
+                None => {
+                    answers.push(KnownAnswers::new(check.day, check.input.clone()));
+                    answers.len() - 1
+                }
+            };
+
+            answers[index].set_part(check.part, answer.clone());
+            recorded += 1;
+        }
+    }
+
+    answers.sort_by(|a, b| (a.day, &a.input).cmp(&(b.day, &b.input)));
+
+    recorded
+}
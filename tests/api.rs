@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use adventofcode_2020::days::{day08, day11, day14, day19, day20};
+
+#[test]
+fn game_console_detects_cycle() {
+    let mut console = day08::GameConsole::new(
+        "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6",
+    )
+    .unwrap();
+
+    assert_eq!(day08::run_and_detect_cycle(&mut console), Ok(true));
+    assert_eq!(console.acc(), 5);
+
+    console.reset();
+    assert_eq!(console.pc(), 0);
+    assert_eq!(console.acc(), 0);
+}
+
+#[test]
+fn seating_grid_fills_empty_seats() {
+    let grid = day11::parse(
+        "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\n\
+         L.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL",
+    )
+    .unwrap();
+    let next = grid.apply_round_adjacent();
+
+    assert_eq!(grid.get(0, 0), Some(&day11::Tile::Empty));
+    assert_eq!(next.get(0, 0), Some(&day11::Tile::Occupied));
+    assert_eq!(next.get(1, 0), Some(&day11::Tile::Floor));
+    assert_eq!(day11::part1(&grid), Ok(37));
+}
+
+#[test]
+fn mask_v2_expands_floating_bits() {
+    let mask = day14::MaskV2::from_str("000000000000000000000000000000X1001X").unwrap();
+    let mut addresses: Vec<u64> = mask.addresses(42).collect();
+    addresses.sort_unstable();
+
+    assert_eq!(addresses, vec![26, 27, 58, 59]);
+}
+
+#[test]
+fn rules_match_messages() {
+    let (rules, messages) = day19::parse(
+        "0: 4 1 5\n1: 2 3 | 3 2\n2: 4 4 | 5 5\n3: 4 5 | 5 4\n4: \"a\"\n5: \"b\"\n\n\
+         ababbb\nbababa\nabbbab\naaabbb\naaaabbb",
+    )
+    .unwrap();
+
+    assert_eq!(rules.matches("ababbb"), Ok(true));
+    assert_eq!(rules.matches("bababa"), Ok(false));
+    assert_eq!(day19::count_matching(&rules, &messages), Ok(2));
+}
+
+#[test]
+fn tile_transformations_round_trip() {
+    let tile = day20::Tile::from_str(
+        "#...##.#..\n..#.#..#.#\n.###....#.\n###.##.##.\n.###.#####\n\
+         .##.#....#\n#...######\n.....#..##\n#.####...#\n#.##...##.",
+    )
+    .unwrap();
+    let rotated = tile.rotate_right();
+
+    assert_eq!(rotated.get_col(day20::TILE_WIDTH - 1), tile.get_row(0));
+    assert_eq!(rotated.rotate_right().rotate_right().rotate_right(), tile);
+    assert_eq!(tile.flip_horizontally().flip_horizontally(), tile);
+}
@@ -0,0 +1,69 @@
+use adventofcode_2020::{
+    automaton::{count_live, hex_neighbours, moore, Automaton, Cycle, Sparse},
+    grid::Grid,
+};
+
+fn life(alive: bool, neighbours: &[bool]) -> bool {
+    match count_live(neighbours) {
+        3 => true,
+        2 => alive,
+        _ => false,
+    }
+}
+
+#[test]
+fn blinker_cycles_with_period_two() {
+    let blinker: Sparse<[isize; 2]> = vec![[0, -1], [0, 0], [0, 1]].into_iter().collect();
+    let mut automaton = Automaton::new(blinker.clone(), |_, cell| moore(cell), life);
+
+    assert_eq!(
+        automaton.find_cycle(10),
+        Ok(Cycle {
+            start: 0,
+            period: 2
+        })
+    );
+
+    let mut automaton = Automaton::new(blinker.clone(), |_, cell| moore(cell), life);
+    automaton.step();
+    assert!(automaton.space().contains(&[-1, 0]));
+    assert!(!automaton.space().contains(&[0, -1]));
+    assert_eq!(
+        automaton.run_until_stable(10),
+        Err("not stable after 10 generations".to_string())
+    );
+}
+
+#[test]
+fn block_is_stable_on_a_dense_grid() {
+    let grid: Grid<bool> = Grid::parse("....\n.##.\n.##.\n....").unwrap();
+    let mut automaton = Automaton::new(
+        grid.clone(),
+        |grid: &Grid<bool>, cell| grid.neighbours(cell).collect(),
+        life,
+    );
+
+    assert_eq!(automaton.run_until_stable(10), Ok(1));
+    assert_eq!(automaton.generation(), 1);
+    assert_eq!(automaton.into_space(), grid);
+}
+
+#[test]
+fn moore_neighbourhood_grows_with_dimensions() {
+    assert_eq!(moore([0, 0]).len(), 8);
+    assert_eq!(moore([0, 0, 0]).len(), 26);
+    assert_eq!(moore([5, 5, 5, 5]).len(), 80);
+    assert!(!moore([1, 2, 3]).contains(&[1, 2, 3]));
+}
+
+#[test]
+fn hex_tiles_have_six_distinct_neighbours() {
+    let mut neighbours = hex_neighbours((0, 0));
+    neighbours.sort_unstable();
+    neighbours.dedup();
+
+    assert_eq!(neighbours.len(), 6);
+    assert!(neighbours
+        .iter()
+        .all(|n| hex_neighbours(*n).contains(&(0, 0))));
+}
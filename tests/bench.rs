@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use adventofcode_2020::{
+    bench::{self, Phase, Timing},
+    bench_day,
+};
+
+#[test]
+fn summary_reports_min_median_max() {
+    let timing = Timing {
+        day: 1,
+        phase: Phase::Part1,
+        samples: [40, 10, 30, 20]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect(),
+    };
+    let summary = timing.summary();
+
+    assert_eq!(summary.runs, 4);
+    assert_eq!(summary.min, Duration::from_millis(10));
+    assert_eq!(summary.median, Duration::from_millis(25));
+    assert_eq!(summary.max, Duration::from_millis(40));
+}
+
+#[test]
+fn csv_round_trips() {
+    let timings = bench_day(8, include_str!("fixtures/day08.txt"), 3).unwrap();
+    let summaries: Vec<_> = timings.iter().map(Timing::summary).collect();
+    let csv = bench::to_csv(&summaries);
+
+    assert_eq!(csv.lines().next(), Some(bench::CSV_HEADER));
+    assert_eq!(
+        bench::from_csv(&csv)
+            .unwrap()
+            .into_values()
+            .collect::<Vec<_>>(),
+        summaries
+    );
+}
@@ -0,0 +1,112 @@
+use adventofcode_2020::{
+    bigint::{BigUint, Number},
+    days::day18,
+    gen::Rng,
+};
+
+const CASES: usize = 1000;
+
+fn big(s: &str) -> BigUint {
+    s.parse().unwrap()
+}
+
+#[test]
+fn arithmetic_matches_u128() {
+    let rng = &mut Rng::new(1);
+
+    for _ in 0..CASES {
+        let a = rng.next_u64() as u128 * rng.next_u64().max(1) as u128;
+        let b = rng.next_u64() as u128 >> rng.below(64);
+        let (x, y) = (BigUint::from(a), BigUint::from(b));
+
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(big(&a.to_string()), x);
+        assert_eq!(x.cmp(&y), a.cmp(&b), "{} <=> {}", a, b);
+        if let Some(sum) = a.checked_add(b) {
+            assert_eq!(&x + &y, BigUint::from(sum), "{} + {}", a, b);
+        }
+        if let Some(product) = (a >> 64).checked_mul(b) {
+            assert_eq!(
+                BigUint::from(a >> 64) * y.clone(),
+                BigUint::from(product),
+                "{} * {}",
+                a >> 64,
+                b
+            );
+        }
+        if b > 0 {
+            assert_eq!(&x % &y, BigUint::from(a % b), "{} % {}", a, b);
+            assert_eq!(
+                x.div_rem(&y).map(|(q, _)| q),
+                Some(BigUint::from(a / b)),
+                "{} / {}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn large_values_round_trip() {
+    let two_200 = (0..200).fold(BigUint::from(1u8), |n, _| &n + &n);
+    assert_eq!(
+        two_200.to_string(),
+        "1606938044258990275541962092341162602522202993782792835301376"
+    );
+    assert_eq!(big(&two_200.to_string()), two_200);
+    assert_eq!(
+        (two_200 + BigUint::from(12345u32)) % big("10000000000000000000000007"),
+        big("1229010114410115596141587")
+    );
+
+    let three_100 = (0..100).fold(BigUint::from(1u8), |n, _| n * BigUint::from(3u8));
+    let two_70 = BigUint::from(1u128 << 70);
+    assert_eq!(
+        three_100.div_rem(&two_70),
+        Some((
+            big("436541740334249833235548125"),
+            big("993139557134778242001")
+        ))
+    );
+    assert_eq!(three_100.div_rem(&BigUint::zero()), None);
+
+    assert_eq!(big("000").to_string(), "0");
+    assert_eq!(format!("{:>5}", BigUint::from(42u8)), "   42");
+    assert!("".parse::<BigUint>().is_err());
+    assert!("12a".parse::<BigUint>().is_err());
+    assert!("-1".parse::<BigUint>().is_err());
+}
+
+#[test]
+fn numbers_promote_on_overflow_and_demote_when_they_fit() {
+    let max = Number::from(u128::MAX);
+    let wrapped = max.clone() + Number::from(1u8);
+
+    assert!(matches!(wrapped, Number::Big(_)));
+    assert_eq!(
+        wrapped.to_string(),
+        "340282366920938463463374607431768211456"
+    );
+    assert!(wrapped > max);
+    assert_eq!(
+        wrapped.clone() % Number::from(1u128 << 100),
+        Number::from(0u8)
+    );
+    assert_eq!((wrapped.clone() * wrapped) % max.clone(), Number::from(1u8));
+    assert_eq!(
+        "340282366920938463463374607431768211455".parse::<Number>(),
+        Ok(max)
+    );
+}
+
+#[test]
+fn day18_does_not_overflow() {
+    let nines = vec!["9"; 45];
+    let input = format!("{}\n{}\n", nines.join(" * "), nines.join(" + "));
+    let expressions = day18::parse(&input).unwrap();
+
+    let expected = "8727963568087712425891397479476727340041854";
+    assert_eq!(day18::part1(&expressions).unwrap().to_string(), expected);
+    assert_eq!(day18::part2(&expressions).unwrap().to_string(), expected);
+}
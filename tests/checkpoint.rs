@@ -0,0 +1,169 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use adventofcode_2020::{
+    checkpoint::{self, Checkpoint, Encode, Settings},
+    days::{day15, day17, day23, day24},
+    solve_day, Options,
+};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-checkpoint-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn always(dir: &Path) -> Settings {
+    Settings {
+        dir: Some(dir.to_path_buf()),
+        interval: Duration::ZERO,
+        progress: false,
+    }
+}
+
+/// A running sum of step numbers, which depends on every step having run exactly once.
+#[derive(Debug, Default)]
+struct Sum {
+    step: u64,
+    total: u64,
+}
+
+impl Checkpoint for Sum {
+    fn save(&self, out: &mut Vec<u8>) {
+        (self.step, self.total).encode(out);
+    }
+
+    fn restore(&mut self, input: &mut &[u8]) -> Result<(), String> {
+        let (step, total) = Encode::decode(input)?;
+        self.step = step;
+        self.total = total;
+        Ok(())
+    }
+}
+
+fn add(sum: &mut Sum) {
+    sum.step += 1;
+    sum.total += sum.step;
+}
+
+#[test]
+fn values_round_trip() {
+    let mut out = Vec::new();
+    let set: HashSet<[isize; 3]> = vec![[1, -2, 3], [-4, 5, 6]].into_iter().collect();
+    let map: BTreeMap<usize, usize> = vec![(0, 1), (3, 4)].into_iter().collect();
+    set.encode(&mut out);
+    map.encode(&mut out);
+    vec![u32::MAX, 0].encode(&mut out);
+
+    let input = &mut out.as_slice();
+    assert_eq!(HashSet::decode(input), Ok(set));
+    assert_eq!(BTreeMap::decode(input), Ok(map));
+    assert_eq!(Vec::<u32>::decode(input), Ok(vec![u32::MAX, 0]));
+    assert!(input.is_empty());
+    assert!(u64::decode(&mut &[1, 2, 3][..]).is_err());
+}
+
+#[test]
+fn interrupted_runs_resume_where_they_stopped() {
+    let dir = scratch_dir("resume");
+    let total = 100_000;
+
+    let interrupted = panic::catch_unwind(AssertUnwindSafe(|| {
+        checkpoint::with(always(&dir), || {
+            checkpoint::simulate("sum", "steps", total, &mut Sum::default(), |sum| {
+                add(sum);
+                assert!(sum.step < 60_000, "interrupted");
+            })
+        })
+    }));
+    assert!(interrupted.is_err());
+
+    let mut initial = Vec::new();
+    Sum::default().save(&mut initial);
+    let path = checkpoint::path(&dir, "sum", total, &initial);
+    assert!(path.is_file());
+
+    // The panic must not leave the settings behind for simulations outside `with`.
+    let ignored = checkpoint::path(&dir, "sum", 10, &initial);
+    fs::write(&ignored, "aoc-checkpoint 1\n\x05").unwrap();
+    assert_eq!(
+        checkpoint::simulate("sum", "steps", 10, &mut Sum::default(), add),
+        Ok(())
+    );
+    fs::remove_file(ignored).unwrap();
+
+    let mut sum = Sum::default();
+    let mut steps = 0;
+    checkpoint::with(always(&dir), || {
+        checkpoint::simulate("sum", "steps", total, &mut sum, |sum| {
+            steps += 1;
+            add(sum);
+        })
+    })
+    .unwrap();
+
+    assert_eq!(sum.total, total * (total + 1) / 2);
+    assert!(steps < total && steps > total - 60_000, "{} steps", steps);
+    assert!(!path.exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn corrupt_checkpoints_are_reported() {
+    let dir = scratch_dir("corrupt");
+    fs::create_dir_all(&dir).unwrap();
+
+    let mut initial = Vec::new();
+    Sum::default().save(&mut initial);
+    let path = checkpoint::path(&dir, "sum", 10, &initial);
+    fs::write(&path, "aoc-checkpoint 1\n\x05").unwrap();
+
+    let result = checkpoint::with(always(&dir), || {
+        checkpoint::simulate("sum", "steps", 10, &mut Sum::default(), add)
+    });
+    assert!(result.unwrap_err().contains("truncated"));
+    assert!(checkpoint::path(&dir, "sum", 11, &initial) != path);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn simulations_checkpoint_without_changing_answers() {
+    let dir = scratch_dir("days");
+
+    checkpoint::with(always(&dir), || {
+        let input = day15::parse("0,3,6\n").unwrap();
+        assert_eq!(day15::part1(&input, &Default::default()), Ok(436));
+
+        let input = day17::parse(".#.\n..#\n###\n").unwrap();
+        assert_eq!(day17::part1(&input, &Default::default()), Ok(112));
+
+        let input = day23::parse("389125467\n").unwrap();
+        assert_eq!(
+            day23::part1(&input, &Default::default()),
+            Ok("67384529".to_string())
+        );
+
+        let input = day24::parse(include_str!("fixtures/day24.txt")).unwrap();
+        assert_eq!(day24::part2(&input, &Default::default()), Ok(2208));
+    });
+    assert_eq!(
+        fs::read_dir(&dir).map(|files| files.count()).unwrap_or(0),
+        0
+    );
+
+    let options = Options {
+        part: Some(1),
+        checkpoint: Some(dir.clone()),
+        ..Options::default()
+    };
+    let answers = solve_day(15, "0,3,6\n", &options).unwrap();
+    assert_eq!(answers.parts[0].answer, "436");
+
+    let _ = fs::remove_dir_all(dir);
+}
@@ -0,0 +1,148 @@
+use std::{collections::HashMap, env, fs, time::Duration};
+
+use adventofcode_2020::{
+    client::{parse_verdict, session, Client, Http, Response, Verdict},
+    params::parse_config,
+    verify::{self, KnownAnswers},
+};
+
+/// Serves canned responses by URL and records every request it receives.
+#[derive(Default)]
+struct StandIn {
+    inputs: HashMap<String, String>,
+    verdicts: Vec<&'static str>,
+    requests: Vec<String>,
+}
+
+impl Http for StandIn {
+    fn get(&mut self, url: &str, session: &str) -> Result<Response, String> {
+        self.requests.push(format!("GET {}", url));
+
+        Ok(match self.inputs.get(url) {
+            _ if session != "secret" => Response {
+                status: 400,
+                body: "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+                    .to_string(),
+            },
+            Some(input) => Response {
+                status: 200,
+                body: input.clone(),
+            },
+            None => Response {
+                status: 404,
+                body: String::new(),
+            },
+        })
+    }
+
+    fn post(&mut self, url: &str, _: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        self.requests.push(format!("POST {} {:?}", url, form));
+
+        Ok(Response {
+            status: 200,
+            body: format!("<article><p>{}</p></article>", self.verdicts.remove(0)),
+        })
+    }
+}
+
+fn client(name: &str, http: StandIn) -> Client<StandIn> {
+    let dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut client = Client::new(http, "secret".to_string());
+    client.base_url = "http://stand-in/2020".to_string();
+    client.cache_dir = dir;
+    client.throttle = Duration::ZERO;
+    client
+}
+
+#[test]
+fn inputs_are_fetched_once() {
+    let mut http = StandIn::default();
+    http.inputs.insert(
+        "http://stand-in/2020/day/1/input".to_string(),
+        "1721\n979\n".to_string(),
+    );
+    let mut client = client("fetch", http);
+
+    let path = client.fetch(1).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n");
+    assert_eq!(client.fetch(1), Ok(path));
+    assert_eq!(client.http.requests.len(), 1);
+
+    assert!(client.fetch(2).unwrap_err().contains("not available"));
+
+    client.session = "stale".to_string();
+    assert!(client.fetch(3).unwrap_err().contains("session"));
+    assert!(!client.cache_dir.join("3.txt").exists());
+
+    fs::remove_dir_all(&client.cache_dir).unwrap();
+}
+
+#[test]
+fn submissions_are_recorded_and_not_repeated() {
+    let http = StandIn {
+        verdicts: vec![
+            "That's not the right answer; your answer is too high.",
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait.",
+            "That's the right answer!  You are one gold star closer to saving your vacation.",
+        ],
+        ..StandIn::default()
+    };
+    let mut client = client("submit", http);
+    let mut answers = Vec::new();
+
+    assert_eq!(
+        client.submit(1, 1, "900", &mut answers),
+        Ok(Verdict::Incorrect(Some("too high".to_string())))
+    );
+    assert!(client.submit(1, 1, "900", &mut answers).is_err());
+    assert_eq!(
+        client.submit(1, 1, "800", &mut answers),
+        Ok(Verdict::TooSoon(Some("42s".to_string())))
+    );
+    assert_eq!(
+        client.submit(1, 1, "800", &mut answers),
+        Ok(Verdict::Correct)
+    );
+    assert!(client.submit(1, 1, "800", &mut answers).is_err());
+    assert!(client.submit(1, 1, "700", &mut answers).is_err());
+    assert_eq!(client.http.requests.len(), 3);
+    assert!(client.http.requests[0].contains(r#"("level", "1"), ("answer", "900")"#));
+
+    let mut expected = KnownAnswers::new(1, client.cache_dir.join("1.txt"));
+    expected.part1 = Some("800".to_string());
+    expected.rejected1 = vec!["900".to_string()];
+    assert_eq!(answers, vec![expected]);
+    assert_eq!(
+        verify::parse_answers(&verify::to_toml(&answers)).unwrap(),
+        answers
+    );
+
+    fs::remove_dir_all(&client.cache_dir).unwrap();
+}
+
+#[test]
+fn verdicts_and_sessions_are_parsed() {
+    assert_eq!(
+        parse_verdict(
+            "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+        ),
+        Ok(Verdict::AlreadySolved)
+    );
+    assert_eq!(
+        parse_verdict("That's not the right answer."),
+        Ok(Verdict::Incorrect(None))
+    );
+    assert!(parse_verdict("<html></html>").is_err());
+
+    assert_eq!(
+        session(Some("session = \"abc123\"\n\n[day9]\npreamble = 5\n")),
+        Ok("abc123".to_string())
+    );
+    assert!(session(Some("session = 5\n")).is_err());
+    assert_eq!(
+        parse_config("session = \"abc123\"\n").map(|c| c.len()),
+        Ok(0)
+    );
+}
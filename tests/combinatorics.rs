@@ -0,0 +1,116 @@
+use adventofcode_2020::{
+    combinatorics::{combinations, k_sum, range_with_sum, windows_with_sums},
+    days::day09,
+    gen::Rng,
+};
+
+const CASES: usize = 300;
+
+fn random(rng: &mut Rng, max: i64) -> Vec<u32> {
+    (0..rng.below(12))
+        .map(|_| rng.range(0..=max) as u32)
+        .collect()
+}
+
+fn binomial(n: usize, k: usize) -> usize {
+    (0..k).fold(1, |c, i| c * (n - i) / (i + 1))
+}
+
+#[test]
+fn combinations_pick_distinct_indices_in_order() {
+    let items = [1, 1, 2, 3];
+    let pairs: Vec<Vec<&u32>> = combinations(&items, 2).collect();
+
+    assert_eq!(pairs.len(), 6);
+    assert_eq!(pairs[0], vec![&1, &1]);
+    assert_eq!(pairs[5], vec![&2, &3]);
+    assert_eq!(combinations(&items, 0).count(), 1);
+    assert_eq!(combinations(&items, 4).count(), 1);
+    assert_eq!(combinations(&items, 5).count(), 0);
+
+    let indices: Vec<usize> = (0..7).collect();
+    for k in 0..=7 {
+        let all: Vec<Vec<&usize>> = combinations(&indices, k).collect();
+        assert_eq!(all.len(), binomial(7, k));
+        assert!(all.iter().all(|c| c.windows(2).all(|w| w[0] < w[1])));
+        assert!(all.windows(2).all(|w| w[0] < w[1]));
+    }
+}
+
+#[test]
+fn k_sum_finds_the_first_combination() {
+    let rng = &mut Rng::new(1);
+
+    for _ in 0..CASES {
+        let items = random(rng, 20);
+        let k = rng.below(5);
+        let target = rng.range(0..=40) as u32;
+        let indices: Vec<usize> = (0..items.len()).collect();
+
+        let expected = combinations(&indices, k)
+            .find(|c| c.iter().map(|&&i| items[i]).sum::<u32>() == target)
+            .map(|c| c.into_iter().copied().collect());
+        assert_eq!(
+            k_sum(&items, k, target),
+            expected,
+            "{:?} k = {} target = {}",
+            items,
+            k,
+            target
+        );
+    }
+
+    // Each entry is only used once, however well it would pair with itself.
+    assert_eq!(k_sum(&[1010, 3], 2, 2020), None);
+    assert_eq!(k_sum(&[1010, 3, 1010], 2, 2020), Some(vec![0, 2]));
+    assert_eq!(k_sum(&[-5i64, 2, 3], 3, 0), Some(vec![0, 1, 2]));
+}
+
+#[test]
+fn windows_and_ranges_match_summing_each_run() {
+    let rng = &mut Rng::new(2);
+
+    for _ in 0..CASES {
+        let items = random(rng, 9);
+        let len = rng.range(1..=4) as usize;
+        let windows: Vec<(&[u32], u32)> = windows_with_sums(&items, len).collect();
+        let expected: Vec<(&[u32], u32)> = items
+            .windows(len)
+            .map(|window| (window, window.iter().sum()))
+            .collect();
+        assert_eq!(windows, expected);
+
+        let target = rng.range(0..=30) as u32;
+        let min_len = rng.below(4);
+        let expected = (0..items.len()).find_map(|end| {
+            (0..=end)
+                .rev()
+                .filter(|start| end + 1 - start >= min_len.max(1))
+                .map(|start| &items[start..=end])
+                .find(|range| range.iter().sum::<u32>() == target)
+        });
+        assert_eq!(
+            range_with_sum(&items, target, min_len),
+            expected,
+            "{:?} target = {} min_len = {}",
+            items,
+            target,
+            min_len
+        );
+    }
+
+    assert_eq!(
+        range_with_sum(&[15, 25, 47, 40], 127, 2),
+        Some(&[15, 25, 47, 40][..])
+    );
+    assert_eq!(range_with_sum(&[127, 1], 127, 2), None);
+
+    let items = [4, 2, 2, 5, 0, 1, 1];
+    assert_eq!(range_with_sum(&items, 2, 2), Some(&[1, 1][..]));
+    assert_eq!(range_with_sum(&items, 1, 1), Some(&[1][..]));
+    assert_eq!(range_with_sum(&items, 1, 2), Some(&[0, 1][..]));
+
+    let params = day09::Params { preamble: 2 };
+    assert_eq!(day09::part2(&items, &params), Ok(2));
+    assert_eq!(day09::reference2(&items, &params), Ok(2));
+}
@@ -0,0 +1,71 @@
+use adventofcode_2020::{
+    crosscheck::{crosscheck, quick_params, Disagreement},
+    crosscheck_day, gen, parse_field, parse_lines, ParseError, Solution,
+};
+
+#[test]
+fn references_agree_on_generated_inputs() {
+    for day in [1, 9, 13, 15, 23] {
+        for seed in 0..3 {
+            let generated = gen::generate(day, seed, None).unwrap();
+            assert_eq!(
+                crosscheck_day(day, &generated.input, &quick_params(day)),
+                Ok(Some(Vec::new())),
+                "day {} seed {}",
+                day,
+                seed
+            );
+        }
+    }
+
+    let input = include_str!("fixtures/day02.txt");
+    assert_eq!(crosscheck_day(2, input, &[]), Ok(None));
+}
+
+/// Sums numbers, but its "optimised" part 1 drops those over 50.
+struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 0;
+
+    type Input = Vec<u32>;
+    type Params = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| parse_field(line, "a number"))
+    }
+
+    fn part1(input: &Self::Input, _: &Self::Params) -> Result<Self::Part1, String> {
+        Ok(input.iter().filter(|&&n| n <= 50).sum())
+    }
+
+    fn part2(input: &Self::Input, _: &Self::Params) -> Result<Self::Part2, String> {
+        Ok(input.iter().sum())
+    }
+
+    fn reference1(input: &Self::Input, _: &Self::Params) -> Option<Result<Self::Part1, String>> {
+        Some(Ok(input.iter().sum()))
+    }
+}
+
+#[test]
+fn disagreements_are_shrunk_to_a_minimal_input() {
+    let input: String = (1..=40)
+        .map(|n| if n == 27 { 70 } else { n })
+        .map(|n| format!("{}\n", n))
+        .collect();
+
+    assert_eq!(
+        crosscheck::<Sum>(&input, &[]),
+        Ok(Some(vec![Disagreement {
+            part: 1,
+            input: "70\n".to_string(),
+            solver: Ok("0".to_string()),
+            reference: Ok("70".to_string()),
+        }]))
+    );
+    assert_eq!(crosscheck::<Sum>("1\n2\n", &[]), Ok(Some(Vec::new())));
+    assert!(crosscheck::<Sum>("x\n", &[]).is_err());
+}
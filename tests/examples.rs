@@ -0,0 +1,321 @@
+use adventofcode_2020::{bigint::Number, days::*};
+
+#[test]
+fn day01() {
+    let input = day01::parse(include_str!("fixtures/day01.txt")).unwrap();
+
+    assert_eq!(day01::part1(&input, &Default::default()), Ok(514579));
+    assert_eq!(day01::part2(&input, &Default::default()), Ok(241861950));
+}
+
+#[test]
+fn day02() {
+    let input = day02::parse(include_str!("fixtures/day02.txt")).unwrap();
+
+    assert_eq!(day02::part1(&input), Ok(2));
+    assert_eq!(day02::part2(&input), Ok(1));
+}
+
+#[test]
+fn day03() {
+    let input = day03::parse(include_str!("fixtures/day03.txt")).unwrap();
+
+    assert_eq!(day03::part1(&input, &Default::default()), Ok(7));
+    assert_eq!(
+        day03::part2(&input, &Default::default()),
+        Ok(Number::from(336u64))
+    );
+}
+
+#[test]
+fn day04() {
+    let input = day04::parse(include_str!("fixtures/day04.txt")).unwrap();
+    assert_eq!(day04::part1(&input), Ok(2));
+
+    let input = day04::parse(include_str!("fixtures/day04-part2.txt")).unwrap();
+    assert_eq!(day04::part1(&input), Ok(8));
+    assert_eq!(day04::part2(&input), Ok(4));
+}
+
+#[test]
+fn day05() {
+    let input = day05::parse(include_str!("fixtures/day05.txt")).unwrap();
+    assert_eq!(day05::part1(&input), Ok(820));
+
+    // No worked example for part 2: fill every seat in rows 1 to 126 but one
+    let mut seats = Vec::new();
+    for row in 1..day05::MAX_ROW {
+        for col in 0..=day05::MAX_COL {
+            if (row, col) != (50, 3) {
+                seats.push(day05::Seat(row, col));
+            }
+        }
+    }
+    assert_eq!(day05::part2(&seats), Ok(403));
+}
+
+#[test]
+fn day06() {
+    let input = day06::parse(include_str!("fixtures/day06.txt")).unwrap();
+
+    assert_eq!(day06::part1(&input), Ok(11));
+    assert_eq!(day06::part2(&input), Ok(6));
+}
+
+#[test]
+fn day07() {
+    let input = day07::parse(include_str!("fixtures/day07.txt")).unwrap();
+    assert_eq!(day07::part1(&input, &Default::default()), Ok(4));
+    assert_eq!(
+        day07::part2(&input, &Default::default()),
+        Ok(Number::from(32u64))
+    );
+
+    let input = day07::parse(include_str!("fixtures/day07-part2.txt")).unwrap();
+    assert_eq!(
+        day07::part2(&input, &Default::default()),
+        Ok(Number::from(126u64))
+    );
+}
+
+#[test]
+fn day08() {
+    let input = day08::parse(include_str!("fixtures/day08.txt")).unwrap();
+
+    assert_eq!(day08::part1(&input), Ok(5));
+    assert_eq!(day08::part2(&input), Ok(8));
+
+    // Patching the `nop` would jump before the first instruction, so only the `jmp` can go.
+    let input = day08::parse("nop -3\nacc +1\njmp -2").unwrap();
+    assert_eq!(day08::part1(&input), Ok(1));
+    assert_eq!(day08::part2(&input), Ok(1));
+
+    let input = day08::parse("acc +0\njmp -1\njmp -2").unwrap();
+    assert_eq!(
+        day08::part2(&input),
+        Err("no single patch terminates the program".to_string())
+    );
+}
+
+#[test]
+fn day09() {
+    // The example uses a 5 number preamble rather than 25
+    let input = day09::parse(include_str!("fixtures/day09.txt")).unwrap();
+    let params = day09::Params { preamble: 5 };
+
+    assert_eq!(day09::part1(&input, &params), Ok(127));
+    assert_eq!(day09::part2(&input, &params), Ok(62));
+}
+
+#[test]
+fn day10() {
+    let input = day10::parse(include_str!("fixtures/day10.txt")).unwrap();
+    assert_eq!(day10::part1(&input), Ok(35));
+    assert_eq!(day10::part2(&input), Ok(Number::from(8u64)));
+
+    let input = day10::parse(include_str!("fixtures/day10-larger.txt")).unwrap();
+    assert_eq!(day10::part1(&input), Ok(220));
+    assert_eq!(day10::part2(&input), Ok(Number::from(19208u64)));
+}
+
+#[test]
+fn day11() {
+    let input = day11::parse(include_str!("fixtures/day11.txt")).unwrap();
+
+    assert_eq!(day11::part1(&input), Ok(37));
+    assert_eq!(day11::part2(&input), Ok(26));
+}
+
+#[test]
+fn day12() {
+    let input = day12::parse(include_str!("fixtures/day12.txt")).unwrap();
+
+    assert_eq!(day12::part1(&input), Ok(25));
+    assert_eq!(day12::part2(&input), Ok(286));
+
+    let crooked = day12::parse("F10\nL45\nF10").unwrap();
+    assert_eq!(
+        day12::part1(&crooked),
+        Err("cannot turn by 45 degrees".to_string())
+    );
+}
+
+#[test]
+fn day13() {
+    let input = day13::parse(include_str!("fixtures/day13.txt")).unwrap();
+    assert_eq!(day13::part1(&input), Ok(295));
+    assert_eq!(day13::part2(&input), Ok(1068781));
+
+    let stopped = day13::parse("10\n0,7\n").unwrap();
+    assert_eq!(day13::part1(&stopped), Ok(28));
+    assert_eq!(day13::reference1(&stopped), Ok(28));
+
+    for (ids, expected) in [
+        ("17,x,13,19", 3417),
+        ("67,7,59,61", 754018),
+        ("67,x,7,59,61", 779210),
+        ("67,7,x,59,61", 1261476),
+        ("1789,37,47,1889", 1202161486),
+    ] {
+        let input = day13::parse(&format!("0\n{}\n", ids)).unwrap();
+        assert_eq!(day13::part2(&input), Ok(expected), "{}", ids);
+    }
+}
+
+#[test]
+fn day14() {
+    let input = day14::parse(include_str!("fixtures/day14.txt")).unwrap();
+    assert_eq!(day14::part1(&input), Ok(165));
+
+    let input = day14::parse(include_str!("fixtures/day14-part2.txt")).unwrap();
+    assert_eq!(day14::part2(&input), Ok(208));
+}
+
+#[test]
+fn day15() {
+    let input = day15::parse(include_str!("fixtures/day15.txt")).unwrap();
+    assert_eq!(day15::part1(&input, &Default::default()), Ok(436));
+    assert_eq!(day15::part2(&input, &Default::default()), Ok(175594));
+
+    for (nums, expected) in [
+        ("1,3,2", 1),
+        ("2,1,3", 10),
+        ("1,2,3", 27),
+        ("2,3,1", 78),
+        ("3,2,1", 438),
+        ("3,1,2", 1836),
+    ] {
+        let input = day15::parse(nums).unwrap();
+        assert_eq!(day15::part1(&input, &Default::default()), Ok(expected));
+    }
+
+    // Starting numbers that repeat.
+    assert_eq!(day15::play(&[1, 1], 3), Ok(1));
+    assert_eq!(day15::play(&[0, 3, 0], 6), Ok(2));
+    assert_eq!(day15::play(&[0, 3, 0], 6), day15::reference(&[0, 3, 0], 6));
+
+    // Games that end before the starting numbers run out.
+    assert_eq!(day15::play(&[0, 3, 6], 2), Ok(3));
+    for turns in 0..=10 {
+        assert_eq!(
+            day15::play(&[0, 3, 6], turns),
+            day15::reference(&[0, 3, 6], turns),
+            "turns = {}",
+            turns
+        );
+    }
+    assert!(day15::play(&[], 5).is_err());
+}
+
+#[test]
+fn day16() {
+    let input = day16::parse(include_str!("fixtures/day16.txt")).unwrap();
+    assert_eq!(day16::part1(&input), Ok(71));
+
+    let input = day16::parse(include_str!("fixtures/day16-part2.txt")).unwrap();
+    let columns: Vec<(usize, &str)> = day16::identify_columns(&input)
+        .unwrap()
+        .into_iter()
+        .map(|(col, rule)| (col, rule.name.as_str()))
+        .collect();
+    assert_eq!(columns, vec![(0, "row"), (1, "class"), (2, "seat")]);
+
+    let params = day16::Params {
+        prefix: "seat".to_string(),
+    };
+    assert_eq!(day16::part2(&input, &params), Ok(Number::from(13u64)));
+}
+
+#[test]
+fn day17() {
+    let input = day17::parse(include_str!("fixtures/day17.txt")).unwrap();
+
+    assert_eq!(day17::part1(&input, &Default::default()), Ok(112));
+    assert_eq!(day17::part2(&input, &Default::default()), Ok(848));
+}
+
+#[test]
+fn day18() {
+    let input = day18::parse(include_str!("fixtures/day18.txt")).unwrap();
+
+    assert_eq!(day18::part1(&input), Ok(Number::from(26457u64)));
+    assert_eq!(day18::part2(&input), Ok(Number::from(694173u64)));
+}
+
+#[test]
+fn day19() {
+    let input = day19::parse(include_str!("fixtures/day19.txt")).unwrap();
+    assert_eq!(day19::part1(&input), Ok(2));
+
+    let input = day19::parse(include_str!("fixtures/day19-part2.txt")).unwrap();
+    assert_eq!(day19::part1(&input), Ok(3));
+    assert_eq!(day19::part2(&input), Ok(12));
+}
+
+#[test]
+fn day20() {
+    let input = day20::parse(include_str!("fixtures/day20.txt")).unwrap();
+
+    assert_eq!(day20::part1(&input), Ok(Number::from(20899048083289u64)));
+    assert_eq!(day20::part2(&input), Ok(273));
+}
+
+#[test]
+fn day21() {
+    let input = day21::parse(include_str!("fixtures/day21.txt")).unwrap();
+
+    assert_eq!(day21::part1(&input), Ok(5));
+    assert_eq!(day21::part2(&input), Ok("mxmxvkd,sqjhc,fvjkl".to_string()));
+
+    let ambiguous = day21::parse("a b (contains dairy)").unwrap();
+    assert_eq!(
+        day21::part2(&ambiguous),
+        Err("allergens are ambiguous (more than one complete matching)".to_string())
+    );
+}
+
+#[test]
+fn day22() {
+    let input = day22::parse(include_str!("fixtures/day22.txt")).unwrap();
+
+    assert_eq!(day22::part1(&input), Ok(306));
+    assert_eq!(day22::part2(&input), Ok(291));
+}
+
+#[test]
+fn day23() {
+    let input = day23::parse(include_str!("fixtures/day23.txt")).unwrap();
+
+    assert_eq!(
+        day23::part1(&input, &Default::default()),
+        Ok("67384529".to_string())
+    );
+    assert_eq!(
+        day23::part1(
+            &input,
+            &day23::Params {
+                part1_moves: 10,
+                ..Default::default()
+            }
+        ),
+        Ok("92658374".to_string())
+    );
+    assert_eq!(day23::part2(&input, &Default::default()), Ok(149245887792));
+}
+
+#[test]
+fn day24() {
+    let input = day24::parse(include_str!("fixtures/day24.txt")).unwrap();
+
+    assert_eq!(day24::part1(&input), Ok(10));
+    assert_eq!(day24::part2(&input, &Default::default()), Ok(2208));
+    assert_eq!(day24::part2(&input, &day24::Params { days: 10 }), Ok(37));
+}
+
+#[test]
+fn day25() {
+    let input = day25::parse(include_str!("fixtures/day25.txt")).unwrap();
+
+    assert_eq!(day25::part1(&input), Ok(14897079));
+    assert_eq!(day25::part2(&input), Ok("Merry Christmas!"));
+}
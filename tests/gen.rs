@@ -0,0 +1,76 @@
+use adventofcode_2020::{days::day08, gen, solve_day, Options, DAYS};
+
+#[test]
+fn generated_inputs_solve_to_their_known_answers() {
+    for day in DAYS {
+        for seed in 0..3 {
+            let generated = gen::generate(day, seed, None).unwrap();
+            if generated.part1.is_none() && generated.part2.is_none() {
+                continue;
+            }
+
+            let answers = solve_day(day, &generated.input, &Options::default())
+                .unwrap_or_else(|e| panic!("day {} seed {}: {}", day, seed, e));
+
+            for answer in &answers.parts {
+                if let Some(expected) = generated.part(answer.part) {
+                    assert_eq!(
+                        answer.answer, expected,
+                        "day {} seed {} part {}",
+                        day, seed, answer.part
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generation_is_deterministic() {
+    for day in DAYS {
+        assert_eq!(
+            gen::generate(day, 42, None).unwrap(),
+            gen::generate(day, 42, None).unwrap()
+        );
+    }
+
+    assert_ne!(
+        gen::generate(20, 1, Some(4)).unwrap(),
+        gen::generate(20, 2, Some(4)).unwrap()
+    );
+}
+
+#[test]
+fn sizes_are_validated() {
+    assert!(gen::generate(20, 0, Some(2)).is_err());
+    assert!(gen::generate(26, 0, None).is_err());
+    assert!(gen::generate(8, 0, Some(gen::day08::MAX_SIZE + 1)).is_err());
+}
+
+#[test]
+fn largest_expense_reports_solve() {
+    for seed in 0..3 {
+        let generated = gen::generate(1, seed, Some(gen::day01::MAX_SIZE)).unwrap();
+        let answers = solve_day(1, &generated.input, &Options::default()).unwrap();
+
+        for answer in &answers.parts {
+            assert_eq!(Some(answer.answer.as_str()), generated.part(answer.part));
+        }
+    }
+}
+
+#[test]
+fn largest_adapter_chains_solve() {
+    let generated = gen::generate(10, 0, Some(gen::day10::MAX_SIZE)).unwrap();
+    let answers = solve_day(10, &generated.input, &Options::default()).unwrap();
+
+    assert_eq!(Some(answers.parts[0].answer.as_str()), generated.part(1));
+}
+
+#[test]
+fn largest_programs_still_parse() {
+    for seed in 0..3 {
+        let generated = gen::generate(8, seed, Some(gen::day08::MAX_SIZE)).unwrap();
+        assert!(day08::parse(&generated.input).is_ok(), "seed {}", seed);
+    }
+}
@@ -0,0 +1,100 @@
+use adventofcode_2020::{
+    bigint::Number,
+    graph::{maximum_matching, unique_matching, Graph},
+};
+
+fn diamond() -> Graph<&'static str, u32> {
+    let mut graph = Graph::new();
+    graph.add_edge("a", "b", 2);
+    graph.add_edge("a", "c", 3);
+    graph.add_edge("b", "d", 1);
+    graph.add_edge("c", "d", 4);
+    graph
+}
+
+#[test]
+fn topological_sort_orders_edges() {
+    let graph = diamond();
+    let sorted = graph.topological_sort().unwrap();
+    let position = |n: &str| sorted.iter().position(|&&m| m == n).unwrap();
+
+    assert_eq!(sorted.len(), 4);
+    assert!(position("a") < position("b") && position("b") < position("d"));
+    assert!(position("a") < position("c") && position("c") < position("d"));
+    assert_eq!(graph.find_cycle(), None);
+}
+
+#[test]
+fn cycles_are_detected() {
+    let mut graph = diamond();
+    graph.add_edge("d", "b", 1);
+
+    assert_eq!(graph.find_cycle(), Some(vec![&"b", &"d"]));
+    assert!(graph.topological_sort().is_err());
+    assert!(graph.count_paths(&"a", &"d").is_err());
+    assert!(graph.count_weighted_paths(&"d").is_err());
+}
+
+#[test]
+fn reachability_follows_edge_direction() {
+    let mut graph = diamond();
+    graph.add_node("e");
+
+    assert_eq!(graph.descendants(&"b"), vec![&"d"]);
+    assert_eq!(graph.ancestors(&"d").len(), 3);
+    assert!(graph.ancestors(&"e").is_empty());
+    assert!(graph.descendants(&"missing").is_empty());
+    assert_eq!(graph.successors(&"a").count(), 2);
+    assert_eq!(
+        graph.predecessors(&"d").collect::<Vec<_>>(),
+        vec![&"b", &"c"]
+    );
+}
+
+#[test]
+fn paths_are_counted_with_memoization() {
+    let graph = diamond();
+
+    assert_eq!(graph.count_paths(&"a", &"d"), Ok(Number::from(2u8)));
+    assert_eq!(graph.count_paths(&"a", &"a"), Ok(Number::from(1u8)));
+    assert_eq!(graph.count_paths(&"d", &"a"), Ok(Number::from(0u8)));
+    assert_eq!(graph.count_paths(&"a", &"missing"), Ok(Number::from(0u8)));
+    // 2 b + 2 d via b, and 3 c + 12 d via c.
+    assert_eq!(graph.count_weighted_paths(&"a"), Ok(Number::from(19u8)));
+
+    let mut ladder: Graph<u32> = Graph::new();
+    for i in 0..200 {
+        ladder.add_edge(i, i + 1, ());
+        ladder.add_edge(i, i + 2, ());
+    }
+    assert!(ladder.count_paths(&0, &150).unwrap() > Number::from(1u128 << 100));
+
+    // Fibonacci(201) paths, well past what a u128 holds.
+    assert_eq!(
+        ladder.count_paths(&0, &200).unwrap().to_string(),
+        "453973694165307953197296969697410619233826"
+    );
+}
+
+#[test]
+fn matchings_pair_distinct_candidates() {
+    let candidates = vec![("x", vec![1, 2, 3]), ("y", vec![1]), ("z", vec![1, 2])];
+
+    assert_eq!(
+        unique_matching(candidates.clone()),
+        Ok(vec![("x", 3), ("y", 1), ("z", 2)])
+    );
+    assert_eq!(maximum_matching(candidates).len(), 3);
+
+    assert!(unique_matching(vec![("x", vec![1, 2]), ("y", vec![1, 2])]).is_err());
+    assert!(unique_matching(vec![("x", vec![1]), ("y", vec![1])]).is_err());
+    assert_eq!(
+        unique_matching(vec![("x", vec![1, 2])]),
+        Err("more than one complete matching".to_string())
+    );
+    assert!(unique_matching(vec![("x", vec![1, 2]), ("y", vec![2, 3])]).is_err());
+    assert_eq!(
+        maximum_matching(vec![("x", vec![1]), ("y", vec![1])]),
+        vec![("x", 1)]
+    );
+}
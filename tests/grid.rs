@@ -0,0 +1,80 @@
+use adventofcode_2020::{
+    grid::{Grid, Transform},
+    ParseError,
+};
+
+const SHAPE: &str = "#..\n##.";
+
+#[test]
+fn parse_and_display_round_trip() {
+    let grid: Grid<bool> = Grid::parse(SHAPE).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(1, 1), Some(&true));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.to_string(), SHAPE);
+
+    assert_eq!(
+        Grid::<bool>::parse("#..\n#x.").unwrap_err(),
+        ParseError::new(2, 2, "x", "`.` or `#`")
+    );
+    assert_eq!(
+        Grid::<bool>::parse("#..\n#.").unwrap_err(),
+        ParseError::new(2, 1, "#.", "a row of 3 cells")
+    );
+}
+
+#[test]
+fn accessors_wrap_and_iterate() {
+    let grid = Grid::from_fn(3, 2, |x, y| x + 10 * y);
+
+    assert_eq!(*grid.get_wrapping(4, -1), 11);
+    assert_eq!(grid.row(1), &[10, 11, 12]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![2, 12]);
+    assert_eq!(grid.columns().count(), 3);
+    assert_eq!(grid.positions().nth(4), Some(((1, 1), &11)));
+    assert_eq!(
+        grid.neighbours((0, 0)).collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1), (1, 1)]
+    );
+    assert_eq!(grid.orthogonal_neighbours((1, 1)).count(), 3);
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+}
+
+#[test]
+fn transforms_form_the_dihedral_group() {
+    let grid: Grid<bool> = Grid::parse(SHAPE).unwrap();
+
+    assert_eq!(grid.rotate_right().to_string(), "##\n#.\n..");
+    assert_eq!(grid.flip_horizontally().to_string(), "..#\n.##");
+    assert_eq!(
+        grid.transform(Transform::RotateLeft),
+        grid.rotate_right().rotate_right().rotate_right()
+    );
+    assert_eq!(
+        grid.transform(Transform::Transpose),
+        grid.rotate_right().flip_horizontally()
+    );
+
+    let mut orientations: Vec<String> = Transform::ALL
+        .iter()
+        .map(|&t| grid.transform(t).to_string())
+        .collect();
+    orientations.sort();
+    orientations.dedup();
+    assert_eq!(orientations.len(), 8);
+}
+
+#[test]
+fn views_read_a_sub_grid() {
+    let grid = Grid::from_fn(4, 4, |x, y| x + 10 * y);
+    let view = grid.view(1, 2, 2, 2);
+
+    assert_eq!(view.get(0, 0), Some(&21));
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(
+        view.rows().collect::<Vec<_>>(),
+        vec![&[21, 22][..], &[31, 32]]
+    );
+    assert_eq!(view.to_grid(), Grid::from_fn(2, 2, |x, y| 21 + x + 10 * y));
+}
@@ -0,0 +1,115 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use adventofcode_2020::{
+    inputs::{self, check, resolve, Problem, Source},
+    solve_day, Options, DAYS,
+};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn paths_resolve_to_files_and_day_files() {
+    let dir = scratch_dir("resolve");
+    fs::write(dir.join("6.txt"), "abc\n").unwrap();
+    let file = Path::new("tests/fixtures/day06.txt").to_path_buf();
+
+    assert_eq!(
+        resolve(6, &[file.clone(), dir.clone()], Path::new("missing")),
+        Ok(vec![Source::File(file), Source::File(dir.join("6.txt"))])
+    );
+    assert!(resolve(7, std::slice::from_ref(&dir), Path::new("missing")).is_err());
+
+    assert_eq!(
+        resolve(6, &[], &dir),
+        Ok(vec![Source::File(dir.join("6.txt"))])
+    );
+    assert_eq!(resolve(7, &[], &dir), Ok(vec![Source::Stdin]));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn broken_inputs_are_detected() {
+    assert_eq!(check("1\n2\n"), None);
+    assert_eq!(check(""), Some(Problem::Empty));
+    assert_eq!(check(" \n\n"), Some(Problem::Empty));
+    assert_eq!(check("1\n\0\0"), Some(Problem::Binary));
+    assert_eq!(
+        check("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+        Some(Problem::ErrorPage)
+    );
+    assert_eq!(check("1\n2"), Some(Problem::MissingNewline));
+    assert!(!Problem::MissingNewline.is_fatal());
+}
+
+#[test]
+fn load_rejects_fatal_problems_only() {
+    let dir = scratch_dir("load");
+    fs::write(dir.join("empty.txt"), "").unwrap();
+    fs::write(dir.join("short.txt"), "1721\n979").unwrap();
+
+    assert_eq!(
+        inputs::load(Source::File(dir.join("empty.txt"))),
+        Err("input is empty".to_string())
+    );
+    assert!(inputs::load(Source::File(dir.join("absent.txt"))).is_err());
+
+    let input = inputs::load(Source::File(dir.join("short.txt"))).unwrap();
+    assert_eq!(input.text, "1721\n979");
+    assert_eq!(input.warning, Some(Problem::MissingNewline));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn lines_and_blocks_ignore_line_endings() {
+    let input = "\u{feff}a b \r\nc\r\n\r\n \r\nd\n\n";
+
+    assert_eq!(
+        inputs::lines(input).collect::<Vec<_>>(),
+        vec!["a b", "c", "", "", "d", ""]
+    );
+    assert_eq!(
+        inputs::blocks(input).collect::<Vec<_>>(),
+        vec!["a b \r\nc", "d"]
+    );
+    assert_eq!(inputs::blocks("\n\n").count(), 0);
+}
+
+#[test]
+fn every_day_tolerates_windows_editors() {
+    for day in DAYS {
+        let path = format!("tests/fixtures/day{:02}.txt", day);
+        let input = fs::read_to_string(&path).unwrap();
+        let mangled = format!(
+            "\u{feff}{}",
+            input
+                .lines()
+                .map(|line| format!("{}  \r\n", line))
+                .collect::<String>()
+        );
+        let options = Options {
+            part: Some(1),
+            // The example uses a 5 number preamble
+            params: if day == 9 {
+                vec![("preamble".to_string(), "5".to_string())]
+            } else {
+                Vec::new()
+            },
+            ..Options::default()
+        };
+
+        let answer = |input: &str| {
+            solve_day(day, input, &options).map(|answers| answers.parts[0].answer.clone())
+        };
+        assert_eq!(answer(&mangled).ok(), answer(&input).ok(), "day {}", day);
+        assert!(answer(&input).is_ok(), "day {}", day);
+    }
+}
@@ -0,0 +1,21 @@
+use adventofcode_2020::{days::day23, json, solve, Options};
+
+#[test]
+fn string_answers_keep_their_type() {
+    let input = include_str!("fixtures/day23.txt");
+    let options = Options {
+        part: Some(1),
+        ..Options::default()
+    };
+    let answers = solve::<day23::Day23>(input, &options).unwrap();
+    let line = answers.parts[0].to_json(23, &answers.skipped);
+
+    assert!(line.starts_with(r#"{"day":23,"part":1,"answer":"67384529","elapsed_ms":"#));
+    assert!(line.ends_with(r#","warnings":[]}"#));
+}
+
+#[test]
+fn strings_are_escaped() {
+    assert_eq!(json::string("a \"b\"\n\\"), r#""a \"b\"\n\\""#);
+    assert_eq!(json::string("\u{1}"), r#""\u0001""#);
+}
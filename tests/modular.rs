@@ -0,0 +1,109 @@
+use adventofcode_2020::{
+    gen::Rng,
+    modular::{crt, discrete_log, extended_gcd, gcd, inverse, lcm, modpow, mul_mod},
+};
+
+const CASES: usize = 1000;
+
+#[test]
+fn extended_gcd_satisfies_bezout() {
+    let rng = &mut Rng::new(1);
+
+    for _ in 0..CASES {
+        let a = rng.next_u64() as i128;
+        let b = rng.next_u64() as i128;
+        let (g, x, y) = extended_gcd(a, b);
+
+        assert_eq!(g as u128, gcd(a as u128, b as u128));
+        assert_eq!(a * x + b * y, g, "a = {}, b = {}", a, b);
+        assert_eq!(lcm(a as u128, b as u128).map(|l| l % a as u128), Some(0));
+    }
+}
+
+#[test]
+fn modpow_matches_repeated_multiplication() {
+    let rng = &mut Rng::new(2);
+
+    for _ in 0..CASES {
+        let m = rng.next_u64().max(1);
+        let base = rng.next_u64();
+        let exp = rng.below(50) as u64;
+
+        let expected = (0..exp).fold(1 % m, |acc, _| mul_mod(acc, base, m));
+        assert_eq!(modpow(base, exp, m), expected, "{}^{} mod {}", base, exp, m);
+    }
+
+    assert_eq!(modpow(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+    assert_eq!(modpow(5, 0, 1), 0);
+}
+
+#[test]
+fn inverse_multiplies_to_one() {
+    let rng = &mut Rng::new(3);
+
+    for _ in 0..CASES {
+        let m = rng.next_u64().max(2);
+        let a = rng.next_u64() % m;
+
+        match inverse(a, m) {
+            Some(inv) => assert_eq!(mul_mod(a, inv, m), 1, "{}^-1 mod {}", a, m),
+            None => assert_ne!(gcd(a as u128, m as u128), 1, "{} mod {}", a, m),
+        }
+    }
+}
+
+#[test]
+fn crt_solves_random_systems() {
+    let rng = &mut Rng::new(4);
+
+    for _ in 0..CASES {
+        let x = rng.next_u64() as u128;
+        let congruences: Vec<(u64, u64)> = (0..rng.range(1..=4))
+            .map(|_| {
+                let m = rng.range(1..=1_000_000_000) as u64;
+                ((x % m as u128) as u64, m)
+            })
+            .collect();
+
+        let (solution, modulus) = crt(&congruences).unwrap();
+        assert!(solution < modulus);
+        assert_eq!(x % modulus, solution, "{:?}", congruences);
+        for &(residue, m) in &congruences {
+            assert_eq!(solution % m as u128, residue as u128);
+        }
+    }
+
+    assert_eq!(
+        crt(&[(0, 7), (12, 13), (55, 59), (25, 31), (12, 19)]),
+        Ok((1068781, 3162341))
+    );
+    assert_eq!(crt(&[(1, 4), (3, 6)]), Ok((9, 12)));
+    assert!(crt(&[(1, 4), (2, 6)]).is_err());
+    assert!(crt(&[(1, 0)]).is_err());
+
+    let huge = [(u64::MAX - 1, u64::MAX), (3, u64::MAX - 1)];
+    let (solution, modulus) = crt(&huge).unwrap();
+    assert_eq!(modulus, u64::MAX as u128 * (u64::MAX - 1) as u128);
+    for &(residue, m) in &huge {
+        assert_eq!(solution % m as u128, residue as u128);
+    }
+}
+
+#[test]
+fn discrete_log_finds_smallest_exponent() {
+    let rng = &mut Rng::new(5);
+    let m = 20_201_227;
+
+    for _ in 0..100 {
+        let exp = rng.below(m as usize - 1) as u64;
+        let target = modpow(7, exp, m);
+
+        assert_eq!(discrete_log(7, target, m), Some(exp));
+    }
+
+    assert_eq!(discrete_log(2, 1, 7), Some(0));
+    assert_eq!(discrete_log(2, 4, 7), Some(2));
+    assert_eq!(discrete_log(2, 3, 7), None);
+    assert_eq!(discrete_log(2, 3, 8), None);
+    assert_eq!(discrete_log(2, 1, 0), None);
+}
@@ -0,0 +1,80 @@
+use adventofcode_2020::{
+    days::{day03, day09},
+    params::{parse_config, parse_override, Params},
+    solve_day, Options,
+};
+
+#[test]
+fn params_are_set_by_name() {
+    let mut params = day03::Params::default();
+    params.set("slope", "1:2").unwrap();
+    params.set("slopes", "1:1, 7:1").unwrap();
+
+    assert_eq!(params.slope, day03::Slope { right: 1, down: 2 });
+    assert_eq!(params.slopes.to_string(), "1:1,7:1");
+
+    assert!(params.set("slope", "3:0").is_err());
+    assert!(params.set("slope", "3").is_err());
+    assert!(day09::Params::default().set("preamble", "-1").is_err());
+
+    let err = day09::Params::default().set("window", "5").unwrap_err();
+    assert!(err.contains("preamble"), "{}", err);
+    assert!(().set("anything", "1").is_err());
+}
+
+#[test]
+fn overrides_are_split_by_day() {
+    assert_eq!(
+        parse_override("preamble=5"),
+        Ok((None, "preamble".to_string(), "5".to_string()))
+    );
+    assert_eq!(
+        parse_override("day16.prefix=arrival track"),
+        Ok((Some(16), "prefix".to_string(), "arrival track".to_string()))
+    );
+    assert!(parse_override("preamble").is_err());
+    assert!(parse_override("day26.cycles=1").is_err());
+    assert!(parse_override("nine.preamble=5").is_err());
+}
+
+#[test]
+fn config_tables_become_overrides() {
+    let config = parse_config(
+        "[day3]\nslopes = [\"1:1\", \"3:1\"]\n\n[day9]\npreamble = 5\n\n[day16]\nprefix = \"seat\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(config.len(), 3);
+    assert_eq!(
+        config[&3],
+        vec![("slopes".to_string(), "1:1,3:1".to_string())]
+    );
+    assert_eq!(config[&9], vec![("preamble".to_string(), "5".to_string())]);
+
+    assert!(parse_config("[day0]\ncycles = 1\n").is_err());
+    assert!(parse_config("preamble = 5\n").is_err());
+    assert!(parse_config("[day9]\npreamble = { n = 5 }\n").is_err());
+}
+
+#[test]
+fn solve_applies_params_in_order() {
+    let input = include_str!("fixtures/day09.txt");
+    let options = Options {
+        params: vec![
+            ("preamble".to_string(), "25".to_string()),
+            ("preamble".to_string(), "5".to_string()),
+        ],
+        ..Options::default()
+    };
+
+    let answers = solve_day(9, input, &options).unwrap();
+    let answers: Vec<&str> = answers.parts.iter().map(|p| p.answer.as_str()).collect();
+    assert_eq!(answers, vec!["127", "62"]);
+
+    let options = Options {
+        params: vec![("cycles".to_string(), "5".to_string())],
+        ..Options::default()
+    };
+    assert!(solve_day(9, input, &options).is_err());
+    assert!(solve_day(2, "1-3 a: abcde\n", &options).is_err());
+}
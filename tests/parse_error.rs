@@ -0,0 +1,71 @@
+use adventofcode_2020::{
+    days::{day01, day08, day22},
+    parse_lenient, solve, Options, ParseError,
+};
+
+#[test]
+fn error_points_at_offending_text() {
+    let input = "1721\n979\n36x6\n299";
+    let e = day01::parse(input).unwrap_err();
+
+    assert_eq!(e, ParseError::new(3, 1, "36x6", "an expense entry"));
+    assert_eq!(
+        e.render(input),
+        "line 3, column 1: expected an expense entry, found \"36x6\"\n \
+         --> line 3, column 1\n  |\n3 | 36x6\n  | ^^^^"
+    );
+}
+
+#[test]
+fn within_rebases_onto_enclosing_input() {
+    let input = "nop +0\nacc +1\njmp +x";
+    let e = day08::parse(input).unwrap_err();
+
+    assert_eq!((e.line, e.column, e.text.as_str()), (3, 5, "+x"));
+}
+
+#[test]
+fn lenient_parse_skips_bad_lines() {
+    let input = "1721\n979\nabc\n366\n299\n675\n1456\n";
+    let (entries, skipped) = parse_lenient::<day01::Day01>(input).unwrap();
+
+    assert_eq!(entries, vec![1721, 979, 366, 299, 675, 1456]);
+    assert_eq!(
+        skipped,
+        vec![ParseError::new(3, 1, "abc", "an expense entry")]
+    );
+
+    let options = Options {
+        lenient: true,
+        ..Options::default()
+    };
+    let answers = solve::<day01::Day01>(input, &options).unwrap();
+    assert_eq!(answers.parts[0].answer, "514579");
+    assert!(solve::<day01::Day01>(input, &Options::default()).is_err());
+
+    let messy = "\u{feff}1721 \r\n979\t\r\nabc  \r\n299\r\n";
+    let (entries, skipped) = parse_lenient::<day01::Day01>(messy).unwrap();
+    assert_eq!(entries, vec![1721, 979, 299]);
+    assert_eq!(
+        skipped,
+        vec![ParseError::new(3, 1, "abc", "an expense entry")]
+    );
+}
+
+#[test]
+fn combat_needs_two_decks_of_distinct_cards() {
+    let e = day22::parse("Player 1:\n9\n2\n").unwrap_err();
+    assert_eq!(e.expected, "a second `Player N:` header");
+
+    let e = day22::parse("Player 1:\nPlayer 2:\n").unwrap_err();
+    assert_eq!((e.line, e.text.as_str()), (2, "Player 2:"));
+
+    let e = day22::parse("Player 1:\n9\n2\n\nPlayer 2:\n5\n9\n").unwrap_err();
+    assert_eq!(e, ParseError::new(7, 1, "9", "a card not already dealt"));
+
+    let e = day22::parse("Player 1:\n1\nPlayer 2:\n2\nPlayer 3:\n3\n").unwrap_err();
+    assert_eq!((e.line, e.text.as_str()), (5, "Player 3:"));
+
+    assert!(day22::parse("Player 1:\n1\nPlayer 2:\n").is_err());
+    assert!(day22::parse("").is_err());
+}
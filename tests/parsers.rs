@@ -0,0 +1,403 @@
+use std::{fmt, panic, str::FromStr};
+
+use adventofcode_2020::{
+    days::{
+        day05::Seat,
+        day07::{self, BagRule, BagType},
+        day08, day11, day12, day14,
+        day16::{self, Ticket, TicketRule},
+        day19,
+        day21::{self, Food},
+        day24::{self, Direction},
+    },
+    gen::{self, Rng},
+    inputs,
+    prop::{self, no_shrink},
+    ParseError,
+};
+
+type Parser = fn(&str) -> Result<(), ParseError>;
+
+const PARSERS: &[(u8, Parser)] = &[
+    (7, |s| day07::parse(s).map(drop)),
+    (8, |s| day08::parse(s).map(drop)),
+    (11, |s| day11::parse(s).map(drop)),
+    (12, |s| day12::parse(s).map(drop)),
+    (14, |s| day14::parse(s).map(drop)),
+    (16, |s| day16::parse(s).map(drop)),
+    (19, |s| day19::parse(s).map(drop)),
+    (21, |s| day21::parse(s).map(drop)),
+    (24, |s| day24::parse(s).map(drop)),
+];
+
+/// Checks that whatever `text` parses to prints as something that parses back the same.
+fn round_trip<T>(text: &str) -> Result<(), String>
+where
+    T: FromStr<Err = ParseError> + fmt::Display + PartialEq + fmt::Debug,
+{
+    let parsed = match T::from_str(text) {
+        Ok(parsed) => parsed,
+        Err(_) => return Ok(()),
+    };
+
+    let printed = parsed.to_string();
+    match T::from_str(&printed) {
+        Ok(reparsed) if reparsed == parsed => Ok(()),
+        Ok(reparsed) => Err(format!("{:?} re-parsed as {:?}", printed, reparsed)),
+        Err(e) => Err(format!("{:?} does not re-parse: {}", printed, e)),
+    }
+}
+
+/// Lines of a few generated inputs for `day`, rewritten by `select` or dropped if it gives `None`.
+fn generated_lines<F>(day: u8, select: F) -> Vec<String>
+where
+    F: Fn(&str) -> Option<&str>,
+{
+    (0..4)
+        .flat_map(|seed| {
+            let input = gen::generate(day, seed, None).unwrap().input;
+            inputs::lines(&input)
+                .filter_map(&select)
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn all(line: &str) -> Option<&str> {
+    Some(line)
+}
+
+fn after_colon(line: &str) -> Option<&str> {
+    line.split_once(": ").map(|(_, rule)| rule)
+}
+
+/// Round-trips generated lines, half of them mutated.
+fn fuzz_round_trip<T>(name: &str, lines: Vec<String>)
+where
+    T: FromStr<Err = ParseError> + fmt::Display + PartialEq + fmt::Debug,
+{
+    assert!(!lines.is_empty(), "{} has no lines", name);
+    prop::check(
+        name,
+        |rng| {
+            let line = rng.choose(&lines).clone();
+            if rng.chance(0.5) {
+                prop::mutate(rng, &line)
+            } else {
+                line
+            }
+        },
+        |s: &String| prop::shrink_str(s),
+        |line| round_trip::<T>(line),
+    );
+}
+
+fn number(rng: &mut Rng, max: u64) -> u64 {
+    match rng.below(8) {
+        0 => 0,
+        1 => max,
+        _ => rng.next_u64() % (max.saturating_add(1)).max(1),
+    }
+}
+
+#[test]
+fn malformed_input_is_rejected_without_panicking() {
+    for &(day, parse) in PARSERS {
+        let inputs: Vec<String> = (0..4)
+            .map(|seed| gen::generate(day, seed, None).unwrap().input)
+            .collect();
+        for input in &inputs {
+            parse(input).unwrap_or_else(|e| panic!("day {} generated {}", day, e));
+        }
+
+        prop::check(
+            &format!("day {} parser", day),
+            |rng| {
+                let input = rng.choose(&inputs).clone();
+                prop::mutate(rng, &input)
+            },
+            |s: &String| prop::shrink_str(s),
+            |input| match parse(input) {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    e.render(input);
+                    // Errors at the end of the input point just past its final newline.
+                    let lines = inputs::lines(input).count() + 1;
+                    if e.line == 0 || e.line > lines || e.column == 0 {
+                        Err(format!("error points outside the input: {}", e))
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+        );
+    }
+}
+
+#[test]
+fn models_print_as_they_parse() {
+    assert_eq!(Seat(70, 7).to_string(), "BFFFBBFRRR");
+    fuzz_round_trip::<Seat>("day 5 seats", generated_lines(5, all));
+    fuzz_round_trip::<BagRule>("day 7 rules", generated_lines(7, all));
+    fuzz_round_trip::<day08::Instruction>("day 8 instructions", generated_lines(8, all));
+    fuzz_round_trip::<day12::Action>("day 12 actions", generated_lines(12, all));
+    fuzz_round_trip::<day14::Instruction>("day 14 instructions", generated_lines(14, all));
+    fuzz_round_trip::<TicketRule>(
+        "day 16 rules",
+        generated_lines(16, |line| line.contains(": ").then_some(line)),
+    );
+    fuzz_round_trip::<Ticket>(
+        "day 16 tickets",
+        generated_lines(16, |line| line.contains(',').then_some(line)),
+    );
+    fuzz_round_trip::<day19::Rule>("day 19 rules", generated_lines(19, after_colon));
+    fuzz_round_trip::<Food>("day 21 foods", generated_lines(21, all));
+
+    let grids: Vec<String> = (0..4)
+        .map(|seed| gen::generate(11, seed, None).unwrap().input)
+        .collect();
+    prop::check(
+        "day 11 grids",
+        |rng| {
+            let grid = rng.choose(&grids).clone();
+            prop::mutate(rng, &grid)
+        },
+        |s: &String| prop::shrink_str(s),
+        |input| match day11::parse(input) {
+            Ok(grid) => match day11::parse(&grid.to_string()) {
+                Ok(reparsed) if reparsed == grid => Ok(()),
+                reparsed => Err(format!("{} re-parsed as {:?}", grid, reparsed)),
+            },
+            Err(_) => Ok(()),
+        },
+    );
+
+    let paths = generated_lines(24, all);
+    prop::check(
+        "day 24 paths",
+        |rng| {
+            let path = rng.choose(&paths).clone();
+            prop::mutate(rng, &path)
+        },
+        |s: &String| prop::shrink_str(s),
+        |line| match day24::parse(line) {
+            Ok(paths) => {
+                let printed = day24::to_puzzle_string(&paths);
+                match day24::parse(&printed) {
+                    Ok(reparsed) if reparsed == paths => Ok(()),
+                    reparsed => Err(format!("{:?} re-parsed as {:?}", printed, reparsed)),
+                }
+            }
+            Err(_) => Ok(()),
+        },
+    );
+}
+
+#[test]
+fn parsers_agree_with_generated_models() {
+    prop::check(
+        "day 8 instructions",
+        |rng| {
+            let arg = number(rng, u16::MAX as u64) as u16 as i16;
+            let (opcode, instruction) = *rng.choose(&[
+                ("acc", day08::Instruction::Acc(arg)),
+                ("jmp", day08::Instruction::Jmp(arg)),
+                ("nop", day08::Instruction::Nop(arg)),
+            ]);
+            (format!("{} {:+}", opcode, arg), instruction)
+        },
+        no_shrink,
+        |(text, instruction)| check_parse(text, instruction),
+    );
+
+    prop::check(
+        "day 12 actions",
+        |rng| {
+            let val = number(rng, u16::MAX as u64) as u16;
+            let (letter, action) = *rng.choose(&[
+                ('N', day12::Action::North(val)),
+                ('S', day12::Action::South(val)),
+                ('E', day12::Action::East(val)),
+                ('W', day12::Action::West(val)),
+                ('L', day12::Action::Left(val)),
+                ('R', day12::Action::Right(val)),
+                ('F', day12::Action::Forward(val)),
+            ]);
+            (format!("{}{}", letter, val), action)
+        },
+        no_shrink,
+        |(text, action)| check_parse(text, action),
+    );
+
+    prop::check(
+        "day 14 lines",
+        |rng| {
+            let mask: String = (0..36).map(|_| *rng.choose(&['0', '1', 'X'])).collect();
+            let (addr, val) = (number(rng, u64::MAX), number(rng, u64::MAX));
+            (mask, addr, val)
+        },
+        no_shrink,
+        |(mask, addr, val)| {
+            check_parse(
+                &format!("mask = {}", mask),
+                &day14::Instruction::Mask(mask.clone()),
+            )?;
+            match day14::parse_mem_line(&format!("mem[{}] = {}", addr, val)) {
+                Ok(parsed) if parsed == (*addr, *val) => Ok(()),
+                parsed => Err(format!("parsed {:?}", parsed)),
+            }
+        },
+    );
+
+    prop::check(
+        "day 16 rules",
+        |rng| {
+            let name: Vec<String> = (0..rng.range(1..=3)).map(|_| rng.word(1..=8)).collect();
+            let ranges: Vec<(u16, u16)> = (0..rng.range(1..=3))
+                .map(|_| {
+                    let start = number(rng, 999) as u16;
+                    (start, start + number(rng, 999) as u16)
+                })
+                .collect();
+            let text = format!(
+                "{}: {}",
+                name.join(" "),
+                ranges
+                    .iter()
+                    .map(|(start, end)| format!("{}-{}", start, end))
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            );
+            let rule = TicketRule {
+                name: name.join(" "),
+                ranges,
+            };
+            (text, rule)
+        },
+        no_shrink,
+        |(text, rule)| check_parse(text, rule),
+    );
+
+    prop::check(
+        "day 19 rules",
+        |rng| {
+            if rng.chance(0.3) {
+                let c = (b'a' + rng.below(26) as u8) as char;
+                return (format!("\"{}\"", c), day19::Rule::Direct(c));
+            }
+
+            let alternatives: Vec<Vec<u16>> = (0..rng.range(1..=3))
+                .map(|_| {
+                    (0..rng.range(1..=3))
+                        .map(|_| number(rng, u16::MAX as u64) as u16)
+                        .collect()
+                })
+                .collect();
+            let text = alternatives
+                .iter()
+                .map(|ids| ids.iter().map(u16::to_string).collect::<Vec<_>>().join(" "))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            (text, day19::Rule::Indirect(alternatives))
+        },
+        no_shrink,
+        |(text, rule)| check_parse(text, rule),
+    );
+
+    prop::check(
+        "day 24 paths",
+        |rng| {
+            (0..rng.range(1..=20))
+                .map(|_| {
+                    *rng.choose(&[
+                        Direction::East,
+                        Direction::Southeast,
+                        Direction::Southwest,
+                        Direction::West,
+                        Direction::Northwest,
+                        Direction::Northeast,
+                    ])
+                })
+                .collect::<Vec<_>>()
+        },
+        |path| prop::shrink_vec(path),
+        |path| {
+            let text: String = path
+                .iter()
+                .map(|direction| match direction {
+                    Direction::East => "e",
+                    Direction::Southeast => "se",
+                    Direction::Southwest => "sw",
+                    Direction::West => "w",
+                    Direction::Northwest => "nw",
+                    Direction::Northeast => "ne",
+                })
+                .collect();
+            match day24::parse(&format!("{}\n", text)) {
+                Ok(paths) if paths == [path.clone()] => Ok(()),
+                parsed => Err(format!("{:?} parsed as {:?}", text, parsed)),
+            }
+        },
+    );
+
+    prop::check(
+        "day 7 rules",
+        |rng| {
+            let bag = |rng: &mut Rng| BagType(rng.word(1..=6), rng.word(1..=6));
+            let rule = BagRule {
+                bag: bag(rng),
+                contents: (0..rng.range(0..=4))
+                    .map(|_| (rng.range(1..=9) as u32, bag(rng)))
+                    .collect(),
+            };
+
+            let contents = if rule.contents.is_empty() {
+                "no other bags".to_string()
+            } else {
+                rule.contents
+                    .iter()
+                    .map(|(count, bag)| {
+                        let plural = if *count == 1 { "" } else { "s" };
+                        format!("{} {} {} bag{}", count, bag.0, bag.1, plural)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let text = format!("{} {} bags contain {}.", rule.bag.0, rule.bag.1, contents);
+            (text, rule)
+        },
+        no_shrink,
+        |(text, rule)| check_parse(text, rule),
+    );
+}
+
+fn check_parse<T>(text: &str, expected: &T) -> Result<(), String>
+where
+    T: FromStr<Err = ParseError> + PartialEq + fmt::Debug,
+{
+    match T::from_str(text) {
+        Ok(parsed) if parsed == *expected => Ok(()),
+        parsed => Err(format!("{:?} parsed as {:?}", text, parsed)),
+    }
+}
+
+#[test]
+fn failures_shrink_to_a_minimal_case() {
+    let failure = panic::catch_unwind(|| {
+        prop::check(
+            "strings without a z",
+            |rng| rng.word(20..=40),
+            |s: &String| prop::shrink_str(s),
+            |s| {
+                assert!(!s.contains('z'), "found a z");
+                Ok(())
+            },
+        )
+    })
+    .unwrap_err();
+
+    let message = failure.downcast_ref::<String>().unwrap();
+    assert!(message.contains("panicked: found a z"), "{}", message);
+    assert!(message.ends_with("minimal case: \"z\""), "{}", message);
+}
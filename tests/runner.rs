@@ -0,0 +1,120 @@
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use adventofcode_2020::{
+    inputs::Source,
+    runner::{self, Failure, Job, Status},
+    verify::KnownAnswers,
+    Options,
+};
+
+fn job(day: u8, path: &str) -> Job {
+    Job {
+        day,
+        source: Source::File(PathBuf::from(path)),
+        options: Options::default(),
+    }
+}
+
+#[test]
+fn results_keep_job_order() {
+    let jobs: Vec<Job> = [6, 1, 2, 4, 3]
+        .iter()
+        .map(|&day| job(day, &format!("tests/fixtures/day{:02}.txt", day)))
+        .collect();
+
+    let finished = runner::run(jobs.clone(), 3, runner::DEFAULT_TIMEOUT);
+
+    assert_eq!(
+        finished.iter().map(|f| &f.job).collect::<Vec<_>>(),
+        jobs.iter().collect::<Vec<_>>()
+    );
+    assert!(finished.iter().all(|f| f.result.is_ok()));
+}
+
+#[test]
+fn failures_are_isolated() {
+    let mut slow = job(15, "tests/fixtures/day15.txt");
+    slow.options.part = Some(2);
+    slow.options.params = vec![("part2_turns".to_string(), "4000000000".to_string())];
+
+    let finished = runner::run(
+        vec![
+            job(1, "tests/fixtures/day01.txt"),
+            job(2, "tests/fixtures/missing.txt"),
+            slow,
+        ],
+        2,
+        Duration::from_millis(200),
+    );
+
+    assert!(finished[0].result.is_ok());
+    assert!(matches!(finished[1].result, Err(Failure::Error(_))));
+    assert_eq!(
+        finished[2].result,
+        Err(Failure::Timeout(Duration::from_millis(200)))
+    );
+
+    let rows = runner::rows(&finished, &[]);
+    assert_eq!(
+        rows.iter().map(|row| &row.status).collect::<Vec<_>>(),
+        vec![&Status::Ok, &Status::Ok, &Status::Error, &Status::Timeout]
+    );
+}
+
+#[test]
+fn known_answers_flag_mismatches() {
+    let finished = runner::run(
+        vec![job(1, "tests/fixtures/day01.txt")],
+        1,
+        runner::DEFAULT_TIMEOUT,
+    );
+    let mut known = KnownAnswers::new(1, "tests/fixtures/day01.txt");
+    known.part1 = Some("514579".to_string());
+    known.part2 = Some("1".to_string());
+
+    let rows = runner::rows(&finished, &[known]);
+    assert_eq!(rows[0].status, Status::Ok);
+    assert_eq!(
+        rows[1].status,
+        Status::Mismatch {
+            expected: "1".to_string()
+        }
+    );
+
+    let table = runner::table(&rows, Duration::from_millis(5), false);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with("Day  Part  Answer     Time"));
+    assert!(lines[1].starts_with("1    1     514579"));
+    assert!(lines[2].ends_with("mismatch (expected 1)"));
+    assert!(lines[3].starts_with("Total: 1 ok, 1 mismatch, 0 timeout, 0 error in "));
+    assert!(lines[3].ends_with("(5.0ms wall)"));
+}
+
+#[test]
+fn abandoned_jobs_hold_their_thread_until_they_finish() {
+    let mut slow = job(15, "tests/fixtures/day15.txt");
+    slow.options.part = Some(2);
+    slow.options.params = vec![("part2_turns".to_string(), "3000000".to_string())];
+    let alone = runner::run(vec![slow.clone()], 1, runner::DEFAULT_TIMEOUT)[0].elapsed;
+
+    let start = Instant::now();
+    let finished = runner::run(
+        vec![slow, job(1, "tests/fixtures/day01.txt")],
+        1,
+        alone / 10,
+    );
+
+    assert_eq!(finished[0].result, Err(Failure::Timeout(alone / 10)));
+    assert!(finished[1].result.is_ok());
+    // The second job only starts once the abandoned one is done.
+    assert!(
+        start.elapsed() >= alone * 3 / 4,
+        "{:?} vs {:?}",
+        start.elapsed(),
+        alone
+    );
+}
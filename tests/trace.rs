@@ -0,0 +1,107 @@
+use std::{fs, panic};
+
+use adventofcode_2020::{
+    solve_day,
+    trace::{self, Event},
+    Format, Options,
+};
+
+fn explain(day: u8) -> Vec<(u8, Event)> {
+    let input = fs::read_to_string(format!("tests/fixtures/day{:02}.txt", day)).unwrap();
+    let options = Options {
+        explain: Some(Format::Text),
+        ..Options::default()
+    };
+
+    solve_day(day, &input, &options)
+        .unwrap()
+        .parts
+        .into_iter()
+        .flat_map(|part| {
+            let number = part.part;
+            part.trace.into_iter().map(move |event| (number, event))
+        })
+        .collect()
+}
+
+#[test]
+fn events_are_only_built_while_capturing() {
+    trace::emit(|| panic!("tracing is off"));
+    assert!(!trace::enabled());
+
+    let (answer, events) = trace::capture(|| {
+        trace::emit(|| Event::new("step").with("n", 1usize));
+        42
+    });
+    assert_eq!(answer, 42);
+    assert_eq!(events, vec![Event::new("step").with("n", 1usize)]);
+    assert!(!trace::enabled());
+
+    // A panic must not leave tracing on, nor lose the events of an enclosing capture.
+    let ((), events) = trace::capture(|| {
+        trace::emit(|| Event::new("outer"));
+        let interrupted = panic::catch_unwind(|| trace::capture(|| panic!("interrupted")));
+        assert!(interrupted.is_err());
+        trace::emit(|| Event::new("after"));
+    });
+    assert_eq!(events, vec![Event::new("outer"), Event::new("after")]);
+    assert!(panic::catch_unwind(|| trace::capture(|| panic!("interrupted"))).is_err());
+    assert!(!trace::enabled());
+
+    let input = fs::read_to_string("tests/fixtures/day08.txt").unwrap();
+    let answers = solve_day(8, &input, &Options::default()).unwrap();
+    assert!(answers.parts.iter().all(|part| part.trace.is_empty()));
+}
+
+#[test]
+fn solvers_explain_their_choices() {
+    let patch = explain(8);
+    assert_eq!(patch.len(), 1);
+    assert_eq!(
+        patch[0].1.to_string(),
+        r#"patch line=8 from="jmp -4" to="nop -4""#
+    );
+    assert_eq!(
+        patch[0].1.to_json(8, patch[0].0),
+        r#"{"day":8,"part":2,"event":"patch","line":8,"from":"jmp -4","to":"nop -4"}"#
+    );
+
+    let columns: Vec<String> = explain(16).iter().map(|(_, e)| e.to_string()).collect();
+    assert_eq!(
+        columns,
+        vec![
+            "column index=0 rule=row",
+            "column index=1 rule=class",
+            "column index=2 rule=seat"
+        ]
+    );
+
+    let corners = explain(20);
+    assert_eq!(
+        corners
+            .iter()
+            .filter(|(part, _)| *part == 1)
+            .filter_map(|(_, e)| e.get("tile"))
+            .collect::<Vec<_>>(),
+        vec!["1171", "1951", "2971", "3079"]
+    );
+
+    let allergens = explain(21);
+    assert_eq!(
+        allergens
+            .iter()
+            .filter(|(part, _)| *part == 2)
+            .map(|(_, e)| (e.get("allergen").unwrap(), e.get("ingredient").unwrap()))
+            .collect::<Vec<_>>(),
+        vec![("dairy", "mxmxvkd"), ("fish", "sqjhc"), ("soy", "fvjkl")]
+    );
+}
+
+#[test]
+fn explain_flag_takes_an_optional_format() {
+    assert_eq!(trace::parse_flag("--explain"), Some(Ok(Format::Text)));
+    assert_eq!(trace::parse_flag("--explain=json"), Some(Ok(Format::Json)));
+    assert!(matches!(trace::parse_flag("--explain=xml"), Some(Err(_))));
+    assert_eq!(trace::parse_flag("--explainx"), None);
+    assert_eq!(trace::parse_flag("--lenient"), None);
+}
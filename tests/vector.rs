@@ -0,0 +1,82 @@
+use adventofcode_2020::{gen::Rng, vector::Vector};
+
+const CASES: usize = 1000;
+
+fn random(rng: &mut Rng) -> Vector<2> {
+    Vector([
+        rng.range(-1000..=1000) as isize,
+        rng.range(-1000..=1000) as isize,
+    ])
+}
+
+#[test]
+fn quarter_turns_agree_with_rotation() {
+    let rng = &mut Rng::new(1);
+
+    for _ in 0..CASES {
+        let v = random(rng);
+        let quarter_turns = rng.range(-10..=10) as isize;
+
+        assert_eq!(v.turn_left(quarter_turns), v.rotate(quarter_turns * 90));
+        assert_eq!(v.turn_right(quarter_turns), v.rotate(-quarter_turns * 90));
+        assert_eq!(v.turn_left(quarter_turns).manhattan(), v.manhattan());
+        assert_eq!(v.turn_left(4), v);
+        assert_eq!(v.turn_left(2), -v);
+    }
+
+    assert_eq!(Vector([1, 0]).turn_left(1), Vector([0, 1]));
+    assert_eq!(Vector([10, 4]).turn_right(1), Vector([4, -10]));
+    assert_eq!(Vector([10, 0]).rotate(45), Vector([7, 7]));
+    assert_eq!(Vector([2, 0]).rotate(-60), Vector([1, -2]));
+}
+
+#[test]
+fn distances_satisfy_the_triangle_inequality() {
+    let rng = &mut Rng::new(2);
+
+    for _ in 0..CASES {
+        let (a, b) = (random(rng), random(rng));
+
+        assert!((a + b).manhattan() <= a.manhattan() + b.manhattan());
+        assert!((a + b).chebyshev() <= a.chebyshev() + b.chebyshev());
+        assert!(a.chebyshev() <= a.manhattan() && a.manhattan() <= 2 * a.chebyshev());
+        assert_eq!((a - b).manhattan(), (b - a).manhattan());
+        assert_eq!(a * 3 - a - a, a);
+    }
+
+    assert_eq!(Vector([3, -4, 0]).manhattan(), 7);
+    assert_eq!(Vector([3, -4, 0]).chebyshev(), 4);
+    assert_eq!(Vector::<0>::ZERO.chebyshev(), 0);
+}
+
+#[test]
+fn neighbour_offsets_cover_each_dimension() {
+    assert_eq!(
+        Vector::<1>::moore_offsets(),
+        vec![Vector([-1]), Vector([1])]
+    );
+    assert_eq!(Vector::<2>::moore_offsets().len(), 8);
+    assert_eq!(Vector::<3>::moore_offsets().len(), 26);
+    assert_eq!(Vector::<4>::moore_offsets().len(), 80);
+    assert!(Vector::<3>::moore_offsets()
+        .iter()
+        .all(|offset| offset.chebyshev() == 1));
+
+    let orthogonal = Vector::<3>::orthogonal_offsets();
+    assert_eq!(orthogonal.len(), 6);
+    assert!(orthogonal.iter().all(|offset| offset.manhattan() == 1));
+    assert!(orthogonal
+        .iter()
+        .all(|&offset| orthogonal.contains(&-offset)));
+}
+
+#[test]
+fn offsets_stay_within_unsigned_coordinates() {
+    assert_eq!(Vector([-1, 2]).offset([1, 1]), Some([0, 3]));
+    assert_eq!(Vector([-2, 2]).offset([1, 1]), None);
+    assert_eq!(Vector([1, 0]).offset([usize::MAX, 0]), None);
+    assert_eq!(
+        Vector([isize::MIN]).offset([usize::MAX]),
+        Some([usize::MAX / 2])
+    );
+}
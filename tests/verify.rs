@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use adventofcode_2020::verify::{self, KnownAnswers, Status};
+
+const ANSWERS: &str = r#"
+[[answer]]
+day = 1
+input = "tests/fixtures/day01.txt"
+part1 = 514579
+part2 = "241861950"
+
+[[answer]]
+day = 6
+input = "tests/fixtures/day06.txt"
+part1 = "12"
+"#;
+
+#[test]
+fn reports_matches_mismatches_and_new_answers() {
+    let answers = verify::parse_answers(ANSWERS).unwrap();
+
+    let statuses: Vec<Status> = answers
+        .iter()
+        .flat_map(|known| {
+            let input = std::fs::read_to_string(&known.input).unwrap();
+            verify::check(known, &input)
+        })
+        .map(|check| check.status)
+        .collect();
+
+    assert_eq!(
+        statuses,
+        vec![
+            Status::Match("514579".to_string()),
+            Status::Match("241861950".to_string()),
+            Status::Mismatch {
+                expected: "12".to_string(),
+                actual: "11".to_string(),
+            },
+            Status::New("6".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn days_without_answers_or_inputs_are_missing() {
+    let answers = verify::parse_answers(ANSWERS).unwrap();
+    let checks = verify::verify(&answers, Path::new("tests/no-such-dir"));
+
+    assert_eq!(checks.len(), 50);
+    assert!(checks[2..]
+        .iter()
+        .filter(|check| check.day != 6)
+        .all(|check| check.status == Status::Missing));
+    assert!(checks.iter().any(|check| check.status.is_failure()));
+}
+
+#[test]
+fn recorded_answers_round_trip() {
+    let mut answers = vec![KnownAnswers::new(6, "tests/fixtures/day06.txt")];
+    let checks = verify::check(&answers[0], include_str!("fixtures/day06.txt"));
+
+    assert_eq!(verify::record(&mut answers, &checks), 2);
+    assert_eq!(answers[0].part(1), Some("11"));
+    assert_eq!(
+        verify::parse_answers(&verify::to_toml(&answers)).unwrap(),
+        answers
+    );
+}
+
+#[test]
+fn rejects_invalid_days() {
+    let err = verify::parse_answers("[[answer]]\nday = 26\ninput = \"x\"\n").unwrap_err();
+
+    assert_eq!(
+        err,
+        "invalid answer #1: `day` must be a number from 1 to 25"
+    );
+}